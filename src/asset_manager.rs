@@ -1,5 +1,6 @@
 use crate::AppState;
 use crate::cards::{Ambush, Card, DrawableCard, Exploration};
+use crate::manifest::AssetManifest;
 use crate::resource_tracking::{ResourceTracking, TrackableResource};
 use crate::terrain::{Choice, Terrain};
 use bevy::ecs::system::RunSystemOnce;
@@ -8,9 +9,22 @@ use std::collections::HashMap;
 use strum::IntoEnumIterator;
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(Startup, load_assets);
+    app.insert_resource(SubstitutedAssets::default())
+        .add_systems(Startup, load_assets)
+        .add_systems(
+            PreUpdate,
+            substitute_failed_assets.run_if(in_state(AppState::Loading)),
+        )
+        .add_systems(OnExit(AppState::Loading), report_substituted_assets);
 }
 
+#[derive(Clone, Debug, Deref, Resource)]
+pub struct Manifest(pub Handle<AssetManifest>);
+
+// (failed path, default path) for every asset the manifest pointed to but could not be loaded
+#[derive(Clone, Debug, Default, Deref, Resource)]
+pub struct SubstitutedAssets(pub Vec<(String, String)>);
+
 #[derive(Clone, Debug, Deref, Resource)]
 pub struct CardFronts(pub HashMap<Card, Handle<Image>>);
 
@@ -35,14 +49,11 @@ pub struct PlayerMaps {
     pub side_a: Handle<Image>,
     pub side_a_rect: Rect,
     pub side_b: Handle<Image>,
+    pub side_b_rect: Rect,
 }
 
 fn load_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.insert_resource(CardFronts(HashMap::from_iter(
-        Card::get_paths()
-            .into_iter()
-            .map(|(card, path)| (card, asset_server.load(path))),
-    )));
+    commands.insert_trackable_resource(Manifest(asset_server.load("default.manifest")));
 
     commands.insert_resource(CardBacks {
         exploration: asset_server.load("textures/cards/explorations/back_exploration.png"),
@@ -57,12 +68,106 @@ fn load_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
         side_a: asset_server.load("textures/maps/map_a.png"),
         side_a_rect: Rect::from_corners(Vec2::new(68.0, 200.0), Vec2::new(634.0, 760.0)),
         side_b: asset_server.load("textures/maps/map_b.png"),
+        side_b_rect: Rect::from_corners(Vec2::new(70.0, 200.0), Vec2::new(634.0, 762.0)),
     });
+}
 
-    commands.insert_trackable_resource(TerrainImages(HashMap::from_iter(
-        Terrain::iter()
-            .map(|terrain| (terrain.clone(), asset_server.load(terrain.get_file_path()))),
+fn load_manifest_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    manifest: Res<Manifest>,
+    manifests: Res<Assets<AssetManifest>>,
+) {
+    let manifest = manifests.get(&manifest.0).expect("manifest");
+    commands.insert_resource(CardFronts(HashMap::from_iter(
+        Card::get_paths().into_iter().map(|(card, path)| {
+            let path = manifest.path(&card.key(), &path);
+            (card, asset_server.load(path))
+        }),
     )));
+
+    commands.insert_trackable_resource(TerrainImages(HashMap::from_iter(Terrain::iter().map(
+        |terrain| {
+            let path = manifest.path(&terrain.key(), terrain.get_file_path());
+            (terrain, asset_server.load(path))
+        },
+    ))));
+}
+
+fn substitute_failed_assets(
+    asset_server: Res<AssetServer>,
+    card_fronts: Option<ResMut<CardFronts>>,
+    terrain_images: Option<ResMut<TerrainImages>>,
+    mut substituted_assets: ResMut<SubstitutedAssets>,
+) {
+    let failed = |handle: &Handle<Image>, default: &str| {
+        let path = handle.path().map(|path| path.to_string())?;
+        (asset_server.load_state(handle).is_failed() && path != default).then_some(path)
+    };
+
+    if let Some(mut card_fronts) = card_fronts {
+        let failures = Card::get_paths()
+            .into_iter()
+            .filter_map(|(card, default)| {
+                let path = failed(&card_fronts[&card], &default)?;
+                Some((card, path, default))
+            })
+            .collect::<Vec<_>>();
+        for (card, path, default) in failures {
+            warn!("failed to load {path}, falling back to {default}");
+            card_fronts
+                .0
+                .insert(card, asset_server.load(default.clone()));
+            substituted_assets.0.push((path, default));
+        }
+    }
+
+    if let Some(mut terrain_images) = terrain_images {
+        let failures = terrain_images
+            .iter()
+            .filter_map(|(terrain, handle)| {
+                let path = failed(handle, terrain.get_file_path())?;
+                Some((terrain.clone(), path))
+            })
+            .collect::<Vec<_>>();
+        for (terrain, path) in failures {
+            let default = String::from(terrain.get_file_path());
+            warn!("failed to load {path}, falling back to {default}");
+            terrain_images
+                .0
+                .insert(terrain, asset_server.load(default.clone()));
+            substituted_assets.0.push((path, default));
+        }
+    }
+}
+
+fn report_substituted_assets(substituted_assets: Res<SubstitutedAssets>) {
+    if substituted_assets.is_empty() {
+        return;
+    }
+    let paths = substituted_assets
+        .iter()
+        .map(|(path, default)| format!("  {path} -> {default}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    warn!(
+        "{} assets of the manifest were replaced by their defaults:\n{paths}",
+        substituted_assets.len()
+    );
+}
+
+impl TrackableResource for Manifest {
+    fn get_handles_to_track(&self) -> Vec<UntypedHandle> {
+        vec![self.0.clone().untyped()]
+    }
+
+    fn on_tracked_handles_fully_loaded(&self) -> impl Command {
+        |world: &mut World| {
+            world
+                .run_system_once(load_manifest_assets)
+                .expect("run once")
+        }
+    }
 }
 
 impl TrackableResource for TerrainImages {
@@ -97,12 +202,29 @@ impl TrackableResource for PlayerMaps {
         vec![self.side_a.clone().untyped(), self.side_b.clone().untyped()]
     }
 
+    fn on_tracked_handles_fully_loaded(&self) -> impl Command {
+        |world: &mut World| {
+            let card_fronts = world.resource::<CardFronts>().clone();
+            world.commands().insert_trackable_resource(card_fronts);
+        }
+    }
+}
+
+// tracked last, failed card fronts are substituted while the rest loads and the
+// substitutes are waited for as well
+impl TrackableResource for CardFronts {
+    fn get_handles_to_track(&self) -> Vec<UntypedHandle> {
+        self.values()
+            .map(|handle| handle.clone().untyped())
+            .collect()
+    }
+
     fn on_tracked_handles_fully_loaded(&self) -> impl Command {
         |world: &mut World| {
             let mut next_state = world
                 .get_resource_mut::<NextState<AppState>>()
                 .expect("next state");
-            next_state.set(AppState::InGame);
+            next_state.set(AppState::PickMap);
         }
     }
 }
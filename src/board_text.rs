@@ -0,0 +1,170 @@
+use crate::AppState;
+use crate::asset_manager::TerrainImages;
+use crate::map::{Cell, CellFeature, Grid};
+use crate::rules::FreePlacement;
+use crate::terrain::Terrain;
+use arboard::Clipboard;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            copy_board.run_if(input_just_pressed(KeyCode::F7)),
+            paste_board.run_if(input_just_pressed(KeyCode::F8)),
+        )
+            .run_if(in_state(AppState::InGame)),
+    );
+}
+
+// one symbol per cell, rows from top to bottom separated by `/`
+pub fn board_to_text<'a>(cells: impl IntoIterator<Item = &'a Cell>, grid: &Grid) -> String {
+    let terrains = cells
+        .into_iter()
+        .map(|cell| (cell.index, cell.terrain.symbol()))
+        .collect::<HashMap<_, _>>();
+    (0..grid.dimension.0)
+        .map(|row| {
+            (0..grid.dimension.1)
+                .map(|column| terrains[&(row, column)])
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+pub fn board_from_text(
+    text: &str,
+    grid: &Grid,
+) -> Result<HashMap<(usize, usize), Terrain>, String> {
+    let rows = text.trim().split('/').collect::<Vec<_>>();
+    if rows.len() != grid.dimension.0 {
+        return Err(format!(
+            "expected {} rows, found {}",
+            grid.dimension.0,
+            rows.len()
+        ));
+    }
+    let mut terrains = HashMap::new();
+    for (row, symbols) in rows.iter().enumerate() {
+        if symbols.chars().count() != grid.dimension.1 {
+            return Err(format!(
+                "expected {} cells in row {}",
+                grid.dimension.1,
+                row + 1
+            ));
+        }
+        for (column, symbol) in symbols.chars().enumerate() {
+            let terrain = Terrain::from_symbol(symbol)
+                .ok_or_else(|| format!("unknown terrain symbol `{symbol}`"))?;
+            terrains.insert((row, column), terrain);
+        }
+    }
+    Ok(terrains)
+}
+
+fn copy_board(cells: Query<&Cell>, grid: Res<Grid>) {
+    let text = board_to_text(cells.iter(), &grid);
+    match Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text.clone())) {
+        Ok(()) => info!("copied board: {text}"),
+        Err(error) => warn!("could not copy the board to the clipboard: {error}"),
+    }
+}
+
+fn paste_board(
+    mut cells: Query<(&mut Cell, &mut Sprite)>,
+    grid: Res<Grid>,
+    free_placement: Res<FreePlacement>,
+    terrain_images: Res<TerrainImages>,
+) {
+    if !free_placement.enabled {
+        info!("boards can only be pasted in free placement");
+        return;
+    }
+    let text = match Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+        Ok(text) => text,
+        Err(error) => {
+            warn!("could not read the clipboard: {error}");
+            return;
+        }
+    };
+    let terrains = match board_from_text(&text, &grid) {
+        Ok(terrains) => terrains,
+        Err(error) => {
+            warn!("could not paste the board: {error}");
+            return;
+        }
+    };
+    // mountains are part of the map itself and cannot be moved
+    let mountains_match = cells.iter().all(|(cell, _)| {
+        (cell.terrain == Terrain::Mountain) == (terrains[&cell.index] == Terrain::Mountain)
+    });
+    if !mountains_match {
+        warn!("could not paste the board: mountains do not match this map");
+        return;
+    }
+    let wastelands_empty = cells.iter().all(|(cell, _)| {
+        cell.feature != CellFeature::Wasteland || terrains[&cell.index] == Terrain::None
+    });
+    if !wastelands_empty {
+        warn!("could not paste the board: wastelands cannot be drawn on");
+        return;
+    }
+    for (mut cell, mut sprite) in cells.iter_mut() {
+        let terrain = terrains[&cell.index].clone();
+        if cell.terrain != terrain {
+            sprite.image = terrain_images[&terrain].clone();
+            cell.terrain = terrain;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boards_survive_a_round_trip() {
+        let grid = Grid::with_dimension((2, 3));
+        let text = "T.V/MXW";
+        let cells = board_from_text(text, &grid)
+            .expect("board")
+            .into_iter()
+            .map(|(index, terrain)| Cell {
+                terrain,
+                index,
+                feature: CellFeature::None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(board_to_text(cells.iter(), &grid), text);
+    }
+
+    #[test]
+    fn boards_with_the_wrong_number_of_rows_are_rejected() {
+        let grid = Grid::with_dimension((2, 3));
+        assert_eq!(
+            board_from_text("T.V", &grid),
+            Err(String::from("expected 2 rows, found 1"))
+        );
+    }
+
+    #[test]
+    fn rows_of_the_wrong_width_are_rejected() {
+        let grid = Grid::with_dimension((2, 3));
+        assert_eq!(
+            board_from_text("T.V/MX", &grid),
+            Err(String::from("expected 3 cells in row 2"))
+        );
+    }
+
+    #[test]
+    fn unknown_symbols_are_rejected() {
+        let grid = Grid::with_dimension((2, 3));
+        assert_eq!(
+            board_from_text("T.V/MXQ", &grid),
+            Err(String::from("unknown terrain symbol `Q`"))
+        );
+    }
+}
@@ -112,6 +112,34 @@ pub enum ShapeScoring {
     TheCauldrons41,
 }
 
+impl Season {
+    pub fn name(&self) -> &str {
+        match self {
+            Season::Spring18 => "Spring",
+            Season::Summer19 => "Summer",
+            Season::Fall20 => "Fall",
+            Season::Winter21 => "Winter",
+        }
+    }
+
+    pub fn time_threshold(&self) -> u32 {
+        match self {
+            Season::Spring18 | Season::Summer19 => 8,
+            Season::Fall20 => 7,
+            Season::Winter21 => 6,
+        }
+    }
+
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            Season::Spring18 => Some(Season::Summer19),
+            Season::Summer19 => Some(Season::Fall20),
+            Season::Fall20 => Some(Season::Winter21),
+            Season::Winter21 => None,
+        }
+    }
+}
+
 impl Card {
     pub fn get_paths() -> Vec<(Self, String)> {
         let mut paths = Vec::new();
@@ -137,9 +165,43 @@ impl Card {
 
         paths
     }
+
+    pub fn key(&self) -> String {
+        let name = match self {
+            Card::DrawableCard(DrawableCard::Ambush(card)) => format!("{card:?}"),
+            Card::DrawableCard(DrawableCard::Exploration(card)) => format!("{card:?}"),
+            Card::Season(card) => format!("{card:?}"),
+            Card::Scroll(card) => format!("{card:?}"),
+            Card::Scoring(Scoring::Tree(card)) => format!("{card:?}"),
+            Card::Scoring(Scoring::Farm(card)) => format!("{card:?}"),
+            Card::Scoring(Scoring::House(card)) => format!("{card:?}"),
+            Card::Scoring(Scoring::Shape(card)) => format!("{card:?}"),
+        };
+        format!("card.{name}")
+    }
 }
 
 impl DrawableCard {
+    pub fn time_cost(&self) -> u32 {
+        match self {
+            DrawableCard::Ambush(_) => 0,
+            DrawableCard::Exploration(exploration) => match exploration {
+                Exploration::TempleRuins05 | Exploration::OutpostRuins06 => 0,
+                Exploration::RiftLands17 => 0,
+                Exploration::GreatRiver07
+                | Exploration::Farmland08
+                | Exploration::Hamlet09
+                | Exploration::ForgottenForest10 => 1,
+                Exploration::HinterlandStream11
+                | Exploration::Homestead12
+                | Exploration::Orchard13
+                | Exploration::TreetopVillage14
+                | Exploration::Marshlands15
+                | Exploration::FishingVillage16 => 2,
+            },
+        }
+    }
+
     pub fn generate_choices(
         &self,
         images: &Assets<Image>,
@@ -237,19 +299,19 @@ impl DrawableCard {
                 let terrain_image = images.get(&terrain_images[terrain]).expect(&format!(
                     "image for {terrain:?} should have been full loaded at this point"
                 ));
-                choices.push(Choice {
-                    terrain: terrain.clone(),
-                    image: asset_server.add(generate_choice_image(&tiles, terrain_image)),
-                    tiles: tiles.clone(),
+                choices.push(Choice::new(
+                    terrain.clone(),
+                    asset_server.add(generate_choice_image(&tiles, terrain_image)),
+                    tiles.clone(),
                     with_coin,
-                });
+                ));
             }
         }
         choices
     }
 }
 
-fn generate_choice_image(tiles: &[(usize, usize)], terrain_image: &Image) -> Image {
+pub fn generate_choice_image(tiles: &[(usize, usize)], terrain_image: &Image) -> Image {
     let terrain_size = terrain_image.texture_descriptor.size;
     let (terrain_width, terrain_height) =
         (terrain_size.width as usize, terrain_size.height as usize);
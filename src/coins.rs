@@ -0,0 +1,58 @@
+use crate::map::SelectedChoicePlaced;
+use crate::mountains::MountainSurrounded;
+use crate::{AppState, reset_resource};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(Coins::default())
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (reset_resource::<Coins>, spawn_coin_label),
+        )
+        .add_systems(
+            Update,
+            (
+                collect_coins,
+                update_coin_label.run_if(resource_changed::<Coins>),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+#[derive(Debug, Default, Resource)]
+pub struct Coins(pub u32);
+
+#[derive(Component)]
+struct CoinLabel;
+
+fn collect_coins(
+    mut placed_events: EventReader<SelectedChoicePlaced>,
+    mut surrounded_events: EventReader<MountainSurrounded>,
+    mut coins: ResMut<Coins>,
+) {
+    let placed = placed_events.read().filter(|event| event.with_coin).count();
+    let surrounded = surrounded_events.read().count();
+    if placed + surrounded > 0 {
+        coins.0 += (placed + surrounded) as u32;
+    }
+}
+
+fn spawn_coin_label(mut commands: Commands) {
+    commands.spawn((
+        CoinLabel,
+        StateScoped(AppState::InGame),
+        Text::default(),
+        TextColor(Color::srgb_u8(240, 190, 50)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(40.0),
+            left: Val::Percent(45.0),
+            ..default()
+        },
+    ));
+}
+
+fn update_coin_label(coins: Res<Coins>, mut label: Single<&mut Text, With<CoinLabel>>) {
+    label.0 = format!("Coins: {}", coins.0);
+}
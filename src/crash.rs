@@ -0,0 +1,226 @@
+use crate::board_text::board_to_text;
+use crate::history::{PlacementLog, describe_placement};
+use crate::map::{Cell, Grid, MapSide};
+use crate::{AppState, GameSetup};
+use arboard::Clipboard;
+use bevy::ecs::schedule::common_conditions::any_match_filter;
+use bevy::prelude::*;
+use std::backtrace::Backtrace;
+use std::sync::{Arc, Mutex};
+use std::{fs, panic};
+use strum::IntoEnumIterator;
+
+const CRASH_FILE: &str = "crash.txt";
+
+pub fn plugin(app: &mut App) {
+    let snapshot = CrashSnapshot::default();
+    install_panic_hook(snapshot.clone());
+    if let Some(crash) = fs::read_to_string(CRASH_FILE)
+        .ok()
+        .and_then(PreviousCrash::from_report)
+    {
+        app.insert_resource(crash);
+    }
+    app.insert_resource(snapshot)
+        .add_systems(
+            OnEnter(AppState::PickMap),
+            show_previous_crash.run_if(resource_exists::<PreviousCrash>),
+        )
+        .add_systems(
+            Update,
+            (
+                update_snapshot
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(resource_changed::<PlacementLog>.or(any_match_filter::<Changed<Cell>>)),
+                answer_crash_dialog.run_if(in_state(AppState::PickMap)),
+            ),
+        );
+}
+
+// the game as text, kept up to date so the panic hook can write it without the world
+#[derive(Clone, Default, Resource)]
+struct CrashSnapshot(Arc<Mutex<String>>);
+
+// read from the crash file of the last run, the dialog offers to deal the same game again
+// from its seed, the board and placements in the report are only there for bug reports
+#[derive(Resource)]
+struct PreviousCrash {
+    report: String,
+    seed: u64,
+    map_side: MapSide,
+}
+
+#[derive(Component)]
+struct CrashDialog;
+
+#[derive(Clone, Component, Copy)]
+enum CrashDialogButton {
+    Redeal,
+    CopyReport,
+    Dismiss,
+}
+
+impl PreviousCrash {
+    fn from_report(report: String) -> Option<Self> {
+        let value = |key: &str| {
+            report
+                .lines()
+                .find_map(|line| line.strip_prefix(key))
+                .map(str::trim)
+        };
+        let seed = value("seed:")?.parse().ok()?;
+        let map_side = value("map side:")?;
+        let map_side = MapSide::iter().find(|side| format!("{side:?}") == map_side)?;
+        Some(Self {
+            report,
+            seed,
+            map_side,
+        })
+    }
+}
+
+fn install_panic_hook(snapshot: CrashSnapshot) {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let snapshot = snapshot
+            .0
+            .lock()
+            .map(|snapshot| snapshot.clone())
+            .unwrap_or_default();
+        let report = format!(
+            "{snapshot}\npanic: {info}\n\n{}",
+            Backtrace::force_capture()
+        );
+        match fs::write(CRASH_FILE, report) {
+            Ok(()) => eprintln!("the game crashed, wrote a report to {CRASH_FILE}"),
+            Err(error) => eprintln!("the game crashed, could not write {CRASH_FILE}: {error}"),
+        }
+        default_hook(info);
+    }));
+}
+
+fn update_snapshot(
+    snapshot: Res<CrashSnapshot>,
+    (game_setup, map_side): (Res<GameSetup>, Res<MapSide>),
+    placement_log: Res<PlacementLog>,
+    cells: Query<&Cell>,
+    grid: Res<Grid>,
+) {
+    let placements = placement_log
+        .iter()
+        .map(describe_placement)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text = format!(
+        "seed: {}\nmap side: {:?}\nedicts: {:?}\nboard: {}\nplacements:\n{placements}\n",
+        game_setup.seed,
+        *map_side,
+        game_setup.edicts,
+        board_to_text(cells.iter(), &grid)
+    );
+    if let Ok(mut snapshot) = snapshot.0.lock() {
+        *snapshot = text;
+    }
+}
+
+fn show_previous_crash(mut commands: Commands, crash: Res<PreviousCrash>) {
+    commands.spawn((
+        CrashDialog,
+        StateScoped(AppState::PickMap),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(12.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.9)),
+        GlobalZIndex(10),
+        children![
+            (
+                Text::new("The last game crashed"),
+                TextFont::from_font_size(32.0)
+            ),
+            Text::new(format!(
+                "Seed {} on side {:?} of the map",
+                crash.seed, crash.map_side
+            )),
+            (
+                Node {
+                    column_gap: Val::Px(16.0),
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                },
+                children![
+                    dialog_button(
+                        CrashDialogButton::Redeal,
+                        "Deal this game again from the start"
+                    ),
+                    dialog_button(CrashDialogButton::CopyReport, "Copy report"),
+                    dialog_button(CrashDialogButton::Dismiss, "Dismiss"),
+                ],
+            ),
+        ],
+    ));
+}
+
+fn dialog_button(button: CrashDialogButton, label: &str) -> impl Bundle {
+    (
+        button,
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb_u8(90, 70, 50)),
+        children![Text::new(label)],
+    )
+}
+
+fn answer_crash_dialog(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &CrashDialogButton), Changed<Interaction>>,
+    dialogs: Query<Entity, With<CrashDialog>>,
+    crash: Option<Res<PreviousCrash>>,
+    (mut game_setup, mut map_side): (ResMut<GameSetup>, ResMut<MapSide>),
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(crash) = crash else {
+        return;
+    };
+    for (_, button) in buttons
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        match button {
+            CrashDialogButton::CopyReport => {
+                match Clipboard::new()
+                    .and_then(|mut clipboard| clipboard.set_text(crash.report.clone()))
+                {
+                    Ok(()) => info!("copied the crash report"),
+                    Err(error) => warn!("could not copy the crash report: {error}"),
+                }
+                continue;
+            }
+            // starts over on an empty map, the edicts are dealt from the seed again
+            CrashDialogButton::Redeal => {
+                game_setup.seed = crash.seed;
+                game_setup.edicts.clear();
+                *map_side = crash.map_side;
+                next_state.set(AppState::InGame);
+            }
+            CrashDialogButton::Dismiss => {}
+        }
+        if let Err(error) = fs::remove_file(CRASH_FILE) {
+            warn!("could not remove {CRASH_FILE}: {error}");
+        }
+        commands.remove_resource::<PreviousCrash>();
+        for dialog in dialogs.iter() {
+            commands.entity(dialog).despawn();
+        }
+        return;
+    }
+}
@@ -0,0 +1,74 @@
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy_framepace::{FramepaceSettings, Limiter};
+use std::fs;
+use std::time::Duration;
+use strum::{EnumIter, IntoEnumIterator};
+
+const PROFILE_FILE: &str = "framepace.txt";
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(load_profile()).add_systems(
+        Update,
+        (
+            cycle_profile.run_if(input_just_pressed(KeyCode::F11)),
+            apply_profile.run_if(resource_changed::<FramepaceProfile>),
+        )
+            .chain(),
+    );
+}
+
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, PartialEq, Resource)]
+pub enum FramepaceProfile {
+    BatterySaver,
+    #[default]
+    Standard,
+    Uncapped,
+}
+
+impl FramepaceProfile {
+    // an uncapped frame should still keep up with the standard profile
+    pub fn frame_budget(self) -> Duration {
+        match self {
+            FramepaceProfile::BatterySaver => Duration::from_secs_f64(1.0 / 30.0),
+            FramepaceProfile::Standard | FramepaceProfile::Uncapped => {
+                Duration::from_secs_f64(1.0 / 60.0)
+            }
+        }
+    }
+
+    fn limiter(self) -> Limiter {
+        match self {
+            FramepaceProfile::BatterySaver => Limiter::from_framerate(30.0),
+            FramepaceProfile::Standard => Limiter::from_framerate(60.0),
+            FramepaceProfile::Uncapped => Limiter::Off,
+        }
+    }
+}
+
+// the profile chosen in the last run, the standard one if there is none
+fn load_profile() -> FramepaceProfile {
+    fs::read_to_string(PROFILE_FILE)
+        .ok()
+        .and_then(|name| {
+            FramepaceProfile::iter().find(|profile| format!("{profile:?}") == name.trim())
+        })
+        .unwrap_or_default()
+}
+
+fn cycle_profile(mut profile: ResMut<FramepaceProfile>) {
+    let profiles = FramepaceProfile::iter().collect::<Vec<_>>();
+    let index = profiles
+        .iter()
+        .position(|other| other == &*profile)
+        .expect("every profile");
+    *profile = profiles[(index + 1) % profiles.len()];
+    if let Err(error) = fs::write(PROFILE_FILE, format!("{:?}", *profile)) {
+        warn!("could not save the framepace profile: {error}");
+    }
+}
+
+fn apply_profile(profile: Res<FramepaceProfile>, mut settings: ResMut<FramepaceSettings>) {
+    settings.limiter = profile.limiter();
+    info!("framepace profile: {:?}", *profile);
+}
@@ -0,0 +1,312 @@
+use crate::AppState;
+use crate::asset_manager::{CardFronts, Choices};
+use crate::cards::{
+    Card, DrawableCard, Exploration, FarmScoring, HouseScoring, Scoring, ShapeScoring, TreeScoring,
+};
+use crate::history::PlacementLog;
+use crate::scoring::SeasonScores;
+use crate::terrain::Terrain;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            toggle_gallery.run_if(input_just_pressed(KeyCode::KeyG)),
+            zoom_card,
+            close_zoomed_card,
+        )
+            .run_if(in_state(AppState::InGame)),
+    );
+}
+
+#[derive(Component)]
+struct Gallery;
+
+#[derive(Component)]
+struct GalleryCard(Card, Handle<Image>);
+
+#[derive(Component)]
+struct ZoomedCard;
+
+const CATEGORIES: [&str; 8] = [
+    "Ambushes",
+    "Explorations",
+    "Seasons",
+    "Scrolls",
+    "Tree edicts",
+    "Farm edicts",
+    "House edicts",
+    "Shape edicts",
+];
+
+fn toggle_gallery(
+    mut commands: Commands,
+    gallery: Option<Single<Entity, With<Gallery>>>,
+    card_fronts: Res<CardFronts>,
+) {
+    if let Some(gallery) = gallery {
+        commands.entity(*gallery).despawn();
+        return;
+    }
+
+    let mut categories = CATEGORIES.map(|name| (name, Vec::new()));
+    for (card, _) in Card::get_paths() {
+        let category = match &card {
+            Card::DrawableCard(DrawableCard::Ambush(_)) => 0,
+            Card::DrawableCard(DrawableCard::Exploration(_)) => 1,
+            Card::Season(_) => 2,
+            Card::Scroll(_) => 3,
+            Card::Scoring(Scoring::Tree(_)) => 4,
+            Card::Scoring(Scoring::Farm(_)) => 5,
+            Card::Scoring(Scoring::House(_)) => 6,
+            Card::Scoring(Scoring::Shape(_)) => 7,
+        };
+        let image = card_fronts[&card].clone();
+        categories[category].1.push((card, image));
+    }
+
+    commands
+        .spawn((
+            Gallery,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(20.0)),
+                row_gap: Val::Px(8.0),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.9)),
+            GlobalZIndex(10),
+            // keeps hovers and clicks away from the board underneath
+            FocusPolicy::Block,
+            Pickable::default(),
+        ))
+        .with_children(|parent| {
+            for (name, cards) in categories {
+                parent.spawn(Text::new(name));
+                parent
+                    .spawn(Node {
+                        flex_wrap: FlexWrap::Wrap,
+                        column_gap: Val::Px(8.0),
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for (card, image) in cards {
+                            parent.spawn((
+                                GalleryCard(card, image.clone()),
+                                Button,
+                                Node {
+                                    width: Val::Px(75.0),
+                                    height: Val::Px(100.0),
+                                    ..default()
+                                },
+                                ImageNode::new(image),
+                            ));
+                        }
+                    });
+            }
+        });
+}
+
+// the art next to the rules of the card and how it did in the current game
+fn zoom_card(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &GalleryCard), Changed<Interaction>>,
+    gallery: Single<Entity, With<Gallery>>,
+    choices: Res<Choices>,
+    (placement_log, season_scores): (Res<PlacementLog>, Res<SeasonScores>),
+) {
+    for (_, card) in interactions
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        let name = card.0.key();
+        let name = name.trim_start_matches("card.");
+        commands.entity(*gallery).with_child((
+            ZoomedCard,
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(32.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            children![
+                (
+                    Node {
+                        height: Val::Percent(80.0),
+                        aspect_ratio: Some(0.75),
+                        ..default()
+                    },
+                    ImageNode::new(card.1.clone()),
+                ),
+                (
+                    Node {
+                        width: Val::Px(360.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    children![
+                        (Text::new(name), TextFont::from_font_size(28.0)),
+                        Text::new(rules_text(&card.0, &choices)),
+                        (
+                            Text::new(game_stats(&card.0, &placement_log, &season_scores)),
+                            TextColor(Color::srgb_u8(200, 170, 110)),
+                        ),
+                    ],
+                ),
+            ],
+        ));
+    }
+}
+
+fn rules_text(card: &Card, choices: &Choices) -> String {
+    match card {
+        Card::DrawableCard(DrawableCard::Ambush(_)) => {
+            String::from("Draw the monster shape, every empty space next to monsters costs a point")
+        }
+        Card::DrawableCard(
+            drawable_card @ DrawableCard::Exploration(
+                Exploration::TempleRuins05 | Exploration::OutpostRuins06,
+            ),
+        ) => format!(
+            "Time {}: the next shape has to cover a ruins space",
+            drawable_card.time_cost()
+        ),
+        Card::DrawableCard(drawable_card) => {
+            let mut terrains = Vec::<&Terrain>::new();
+            for choice in choices[drawable_card].iter() {
+                if !terrains.contains(&&choice.terrain) {
+                    terrains.push(&choice.terrain);
+                }
+            }
+            let terrains = terrains
+                .iter()
+                .map(|terrain| format!("{terrain:?}"))
+                .collect::<Vec<_>>()
+                .join(" or ");
+            let coin = if choices[drawable_card].iter().any(|choice| choice.with_coin) {
+                ", one of the shapes earns a coin"
+            } else {
+                ""
+            };
+            format!("Time {}: draw {terrains}{coin}", drawable_card.time_cost())
+        }
+        Card::Season(season) => {
+            let [first, second] = season.edict_slots();
+            format!(
+                "Ends after {} time, then edicts {} and {} are scored",
+                season.time_threshold(),
+                first.letter(),
+                second.letter()
+            )
+        }
+        Card::Scroll(_) => String::from("Holds the edict below it"),
+        Card::Scoring(scoring) => String::from(edict_text(scoring)),
+    }
+}
+
+fn edict_text(scoring: &Scoring) -> &'static str {
+    match scoring {
+        Scoring::Tree(TreeScoring::SentinelWood26) => {
+            "1 point per forest space on the edge of the map"
+        }
+        Scoring::Tree(TreeScoring::Greenbough27) => {
+            "1 point per row and per column with at least one forest space"
+        }
+        Scoring::Tree(TreeScoring::Treetower28) => {
+            "1 point per forest space surrounded on all sides by filled spaces or the edge"
+        }
+        Scoring::Tree(TreeScoring::StonesideForest29) => {
+            "3 points per mountain connected to another mountain by a cluster of forest spaces"
+        }
+        Scoring::Farm(FarmScoring::CanalLake30) => {
+            "1 point per water space next to a farm and per farm space next to a water"
+        }
+        Scoring::Farm(FarmScoring::MagesValley31) => {
+            "2 points per water space and 1 point per farm space next to a mountain"
+        }
+        Scoring::Farm(FarmScoring::TheGoldenGranary32) => {
+            "1 point per water space next to a ruins space, 3 points per farm space on ruins"
+        }
+        Scoring::Farm(FarmScoring::ShoresideExpanse33) => {
+            "3 points per farm or water cluster touching neither the edge nor the other terrain"
+        }
+        Scoring::House(HouseScoring::Wildholds34) => {
+            "8 points per cluster of six or more village spaces"
+        }
+        Scoring::House(HouseScoring::GreatCity35) => {
+            "1 point per space of the largest village cluster not next to a mountain"
+        }
+        Scoring::House(HouseScoring::GreengoldPlains36) => {
+            "3 points per village cluster next to at least three other terrain types"
+        }
+        Scoring::House(HouseScoring::Shieldgate37) => {
+            "2 points per space of the second largest village cluster"
+        }
+        Scoring::Shape(ShapeScoring::Borderlands38) => "6 points per full row or column",
+        Scoring::Shape(ShapeScoring::LostBarony39) => {
+            "3 points per space along one side of the largest square of filled spaces"
+        }
+        Scoring::Shape(ShapeScoring::TheBrokenRoad40) => {
+            "3 points per full diagonal from the left edge to the bottom edge"
+        }
+        Scoring::Shape(ShapeScoring::TheCauldrons41) => {
+            "1 point per empty space surrounded on all sides by filled spaces or the edge"
+        }
+    }
+}
+
+// there is no history across games yet, so the stats cover the current one
+fn game_stats(card: &Card, placement_log: &PlacementLog, season_scores: &SeasonScores) -> String {
+    match card {
+        Card::DrawableCard(drawable_card) => {
+            let placed = placement_log
+                .iter()
+                .filter(|placement| placement.card == *drawable_card)
+                .count();
+            format!("Placed {placed} times this game")
+        }
+        Card::Scoring(scoring) => {
+            let points = season_scores
+                .0
+                .iter()
+                .flat_map(|score| score.edicts.iter())
+                .filter(|(edict, _)| edict == scoring)
+                .map(|(_, points)| *points)
+                .collect::<Vec<_>>();
+            if points.is_empty() {
+                return String::from("Not scored yet this game");
+            }
+            let average = points.iter().sum::<u32>() as f32 / points.len() as f32;
+            format!(
+                "Scored {} times this game, {average:.1} points on average",
+                points.len()
+            )
+        }
+        Card::Season(_) | Card::Scroll(_) => String::new(),
+    }
+}
+
+fn close_zoomed_card(
+    mut commands: Commands,
+    interactions: Query<(Entity, &Interaction), With<ZoomedCard>>,
+) {
+    for (entity, _) in interactions
+        .iter()
+        .filter(|(_, interaction)| **interaction == Interaction::Pressed)
+    {
+        commands.entity(entity).despawn();
+    }
+}
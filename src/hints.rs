@@ -0,0 +1,174 @@
+use crate::map::Cell;
+use crate::placement::{SelectedChoice, SelectedChoiceSpawned};
+use crate::rules::Hardcore;
+use crate::terrain::Terrain;
+use crate::{AppState, MainCamera};
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use std::collections::HashSet;
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.add_event::<ShowHint>()
+        .insert_resource(Hints {
+            enabled: true,
+            shown: HashSet::new(),
+        })
+        .add_systems(
+            Update,
+            (
+                toggle_hints.run_if(input_just_pressed(KeyCode::F1)),
+                trigger_hints,
+                show_hints.run_if(on_event::<ShowHint>),
+                position_hints,
+                dismiss_hints,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Hint {
+    RotateAndFlip,
+    PlaceChoice,
+    InspectMonsters,
+}
+
+#[derive(Debug, Resource)]
+pub struct Hints {
+    pub enabled: bool,
+    shown: HashSet<Hint>,
+}
+
+#[derive(Event)]
+pub struct ShowHint {
+    pub hint: Hint,
+    pub anchor: Entity,
+}
+
+#[derive(Component)]
+struct HintBubble {
+    anchor: Entity,
+    timer: Timer,
+}
+
+impl Hint {
+    fn text(&self) -> &str {
+        match self {
+            Hint::RotateAndFlip => {
+                "Scroll to rotate, press F, H or V to flip, Escape to pick another shape"
+            }
+            Hint::PlaceChoice => "Left click or press P to place the shape here",
+            Hint::InspectMonsters => "Click a monster to inspect its cluster",
+        }
+    }
+}
+
+fn toggle_hints(
+    mut commands: Commands,
+    mut hints: ResMut<Hints>,
+    bubbles: Query<Entity, With<HintBubble>>,
+) {
+    hints.enabled = !hints.enabled;
+    info!("hints enabled: {}", hints.enabled);
+    for bubble in bubbles.iter() {
+        commands.entity(bubble).despawn();
+    }
+}
+
+fn trigger_hints(
+    mut commands: Commands,
+    mut spawned_choices: EventReader<SelectedChoiceSpawned>,
+    changed_choices: Query<(Entity, &SelectedChoice), Changed<SelectedChoice>>,
+    changed_cells: Query<(Entity, &Cell), Changed<Cell>>,
+) {
+    for event in spawned_choices.read() {
+        commands.send_event(ShowHint {
+            hint: Hint::RotateAndFlip,
+            anchor: event.0,
+        });
+    }
+    for (anchor, _) in changed_choices
+        .iter()
+        .filter(|(_, choice)| choice.valid_to_place)
+    {
+        commands.send_event(ShowHint {
+            hint: Hint::PlaceChoice,
+            anchor,
+        });
+    }
+    for (anchor, _) in changed_cells
+        .iter()
+        .filter(|(_, cell)| cell.terrain == Terrain::Monster)
+    {
+        commands.send_event(ShowHint {
+            hint: Hint::InspectMonsters,
+            anchor,
+        });
+    }
+}
+
+fn show_hints(
+    mut commands: Commands,
+    mut event_reader: EventReader<ShowHint>,
+    mut hints: ResMut<Hints>,
+    hardcore: Res<Hardcore>,
+    bubbles: Query<Entity, With<HintBubble>>,
+) {
+    for event in event_reader.read() {
+        if !hints.enabled || hardcore.enabled || !hints.shown.insert(event.hint) {
+            continue;
+        }
+        for bubble in bubbles.iter() {
+            commands.entity(bubble).despawn();
+        }
+        commands.spawn((
+            HintBubble {
+                anchor: event.anchor,
+                timer: Timer::new(Duration::from_secs(6), TimerMode::Once),
+            },
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(6.0)),
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.3, 0.9)),
+            GlobalZIndex(5),
+            children![(Text::new(event.hint.text()), TextFont::from_font_size(16.0))],
+        ));
+    }
+}
+
+fn position_hints(
+    mut commands: Commands,
+    mut bubbles: Query<(Entity, &HintBubble, &mut Node)>,
+    anchors: Query<&GlobalTransform>,
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+) {
+    let (camera, camera_transform) = *camera;
+    for (entity, bubble, mut node) in bubbles.iter_mut() {
+        let Ok(anchor) = anchors.get(bubble.anchor) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if let Ok(position) = camera.world_to_viewport(camera_transform, anchor.translation()) {
+            node.left = Val::Px(position.x + 24.0);
+            node.top = Val::Px(position.y - 24.0);
+        }
+    }
+}
+
+fn dismiss_hints(
+    mut commands: Commands,
+    mut bubbles: Query<(Entity, &mut HintBubble, &Interaction)>,
+    time: Res<Time>,
+) {
+    for (entity, mut bubble, interaction) in bubbles.iter_mut() {
+        if bubble.timer.tick(time.delta()).finished() || *interaction == Interaction::Pressed {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::cards::DrawableCard;
+use crate::map::{Cell, Grid, SelectedChoicePlaced, coordinate};
+use crate::terrain::Terrain;
+use crate::{AppState, reset_resource};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(PlacementLog::default())
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (reset_resource::<PlacementLog>, spawn_sidebar),
+        )
+        .add_systems(
+            Update,
+            (
+                log_placements.run_if(on_event::<SelectedChoicePlaced>),
+                update_entries.run_if(resource_changed::<PlacementLog>),
+                toggle_sidebar,
+                highlight_placement,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+#[derive(Clone, Debug)]
+pub struct Placement {
+    pub turn: usize,
+    pub card: DrawableCard,
+    pub terrain: Terrain,
+    pub tiles: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Default, Deref, DerefMut, Resource)]
+pub struct PlacementLog(pub Vec<Placement>);
+
+#[derive(Component)]
+struct HistoryToggle;
+
+#[derive(Component)]
+struct HistoryEntries;
+
+#[derive(Component)]
+struct HistoryEntry(usize);
+
+// on top of the cell like the paint cursor, so other highlights keep their tint
+#[derive(Component)]
+struct HistoryHighlight;
+
+fn spawn_sidebar(mut commands: Commands) {
+    commands
+        .spawn((
+            StateScoped(AppState::InGame),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                width: Val::Px(300.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            GlobalZIndex(1),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                HistoryToggle,
+                Button,
+                Node {
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BorderRadius::all(Val::Px(4.0)),
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+                children![Text::new("History")],
+            ));
+            parent.spawn((
+                HistoryEntries,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn log_placements(
+    mut event_reader: EventReader<SelectedChoicePlaced>,
+    mut placement_log: ResMut<PlacementLog>,
+) {
+    for placed in event_reader.read() {
+        let turn = placement_log.len() + 1;
+        let placement = Placement {
+            turn,
+            card: placed.card.clone(),
+            terrain: placed.terrain.clone(),
+            tiles: placed.tiles.clone(),
+        };
+        info!("{}", describe_placement(&placement));
+        placement_log.push(placement);
+    }
+}
+
+fn update_entries(
+    mut commands: Commands,
+    placement_log: Res<PlacementLog>,
+    entries: Single<Entity, With<HistoryEntries>>,
+) {
+    commands
+        .entity(*entries)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            for (index, placement) in placement_log.iter().enumerate() {
+                parent.spawn((
+                    HistoryEntry(index),
+                    Button,
+                    Node {
+                        padding: UiRect::all(Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                    children![(
+                        Text::new(describe_placement(placement)),
+                        TextFont::from_font_size(14.0),
+                    )],
+                ));
+            }
+        });
+}
+
+fn toggle_sidebar(
+    toggle: Query<&Interaction, (Changed<Interaction>, With<HistoryToggle>)>,
+    mut entries: Single<&mut Node, With<HistoryEntries>>,
+) {
+    if !toggle
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    entries.display = match entries.display {
+        Display::None => Display::Flex,
+        _ => Display::None,
+    };
+}
+
+fn highlight_placement(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &HistoryEntry), Changed<Interaction>>,
+    placement_log: Res<PlacementLog>,
+    cells: Query<(Entity, &Cell)>,
+    highlights: Query<Entity, With<HistoryHighlight>>,
+    grid: Res<Grid>,
+    mut highlighted_entry: Local<Option<usize>>,
+) {
+    for (interaction, entry) in interactions.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        for highlight in highlights.iter() {
+            commands.entity(highlight).despawn();
+        }
+        if highlighted_entry.take() == Some(entry.0) {
+            continue;
+        }
+        let tiles = &placement_log[entry.0].tiles;
+        for (entity, _) in cells.iter().filter(|(_, cell)| tiles.contains(&cell.index)) {
+            commands.entity(entity).with_child((
+                HistoryHighlight,
+                Sprite {
+                    color: Color::srgba(0.6, 0.8, 1.0, 0.5),
+                    custom_size: Some(grid.cell_size),
+                    ..default()
+                },
+                Pickable::IGNORE,
+                Transform::from_xyz(0.0, 0.0, 0.9),
+            ));
+        }
+        *highlighted_entry = Some(entry.0);
+    }
+}
+
+pub fn describe_placement(placement: &Placement) -> String {
+    let card = match &placement.card {
+        DrawableCard::Ambush(ambush) => format!("{ambush:?}"),
+        DrawableCard::Exploration(exploration) => format!("{exploration:?}"),
+    };
+    let tiles = placement
+        .tiles
+        .iter()
+        .map(|tile| coordinate(*tile))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "{}. {card}: {:?} at {tiles}",
+        placement.turn, placement.terrain
+    )
+}
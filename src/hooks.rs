@@ -0,0 +1,34 @@
+use crate::AppState;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.configure_sets(
+        Update,
+        (
+            GameSet::Draw,
+            GameSet::AfterDraw,
+            GameSet::BeforeScoring,
+            GameSet::Scoring,
+            GameSet::SeasonEnd,
+        )
+            .chain()
+            .run_if(in_state(AppState::InGame)),
+    );
+}
+
+// phases of a frame in the order they run, so additional rules like event cards
+// can be added as systems in one of the sets without touching the game itself:
+// - Draw: the game draws a card and checks whether the season is over
+// - AfterDraw: the drawn card is in place, a `Changed<DrawnCard>` filter tells whether it is new
+// - BeforeScoring: the board is final for this frame, `SeasonEnded` is readable if a season ended
+// - Scoring: the game scores the ended season into `SeasonScores`
+// - SeasonEnd: the scores of the ended season are available
+// the sets only order systems, they run every frame and hooks bring their own run conditions
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub enum GameSet {
+    Draw,
+    AfterDraw,
+    BeforeScoring,
+    Scoring,
+    SeasonEnd,
+}
@@ -1,18 +1,48 @@
 #![allow(dead_code)]
 
 mod asset_manager;
+mod board_text;
 mod cards;
+mod coins;
+mod crash;
 mod deck;
+mod framepace;
+mod gallery;
+mod hints;
+mod history;
+mod hooks;
+mod manifest;
 mod map;
+mod map_picker;
+mod monsters;
+mod mountains;
+mod painting;
+mod palette;
+mod placement;
 mod resource_tracking;
+mod results;
+mod ruins;
+mod rules;
+mod scoring;
+mod season;
+mod shapes;
 mod terrain;
+mod watchdog;
 
 use crate::asset_manager::{CardBacks, CardFronts, Choices};
-use crate::cards::DrawableCard;
 use crate::cards::{Card, Scoring};
+use crate::cards::{DrawableCard, Exploration};
+use crate::hooks::GameSet;
 use crate::map::{
-    Cell, Grid, PlayerMap, SelectedChoicePlaced, is_inside_grid, snap_selected_choice_to_cell,
+    Cell, Grid, LastHoveredCell, PlayerMap, SelectedChoicePlaced, fits_anywhere, is_inside_grid,
+    snap_selected_choice_to_cell,
 };
+use crate::placement::SelectedChoice;
+use crate::ruins::{RuinsPending, RuinsWaived};
+use crate::rules::{FreePlacement, Hardcore};
+use crate::scoring::EdictSlot;
+use crate::season::{end_season_when_time_is_up, season_in_progress};
+use crate::shapes::oriented;
 use crate::terrain::{Choice, Terrain};
 use bevy::ecs::component::HookContext;
 use bevy::ecs::relationship::OrderedRelationshipSourceCollection;
@@ -20,11 +50,13 @@ use bevy::ecs::world::DeferredWorld;
 use bevy::input::common_conditions::input_just_pressed;
 use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 use bevy::window::PrimaryWindow;
 use bevy_framepace::FramepacePlugin;
-use rand::rng;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use std::collections::HashSet;
+use rand::{SeedableRng, random};
+use strum::IntoEnumIterator;
 
 #[derive(Clone, Debug, Default, Deref, Resource)]
 struct WorldPosition(Vec2);
@@ -53,25 +85,54 @@ struct Scroll;
 #[derive(Component)]
 struct ChoiceUI;
 
+// alpha of the backdrop behind the offered choices
+#[derive(Debug, Resource)]
+struct ChoiceDim(f32);
+
+// everything needed to deal the same game again, the edicts are remembered once dealt
+#[derive(Debug, Resource)]
+struct GameSetup {
+    seed: u64,
+    edicts: Vec<Scoring>,
+}
+
+// every shuffle of a game draws from this, seeded from the game setup
+#[derive(Resource)]
+struct GameRng(StdRng);
+
+// the edicts are dealt from their own stream, so a rematch that keeps them shuffles
+// the deck exactly like the first game
+#[derive(Resource)]
+struct EdictRng(StdRng);
+
+const EDICT_SALT: u64 = 0x00ED_1C75;
+
 #[derive(Component)]
 #[component(on_add = mark_disabled_choices)]
 struct DisabledChoice;
 
+#[derive(Component)]
+struct GhostChoice;
+
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, States)]
 enum AppState {
     #[default]
     Loading,
+    // the side of the map is chosen before every new setup
+    PickMap,
     InGame,
+    GameOver,
 }
 
-// TODO: refactor in separate module
-#[derive(Component)]
-struct SelectedChoice {
-    choice: Choice,
-    rotation: f32,
-    valid_to_place: bool,
-    occupied_tiles: Option<Vec<(isize, isize)>>,
-    latest_hovered_cell: Option<Entity>,
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, SubStates)]
+#[source(AppState = AppState::InGame)]
+enum TurnPhase {
+    DrawCard,
+    // setup already deals the first card
+    #[default]
+    ChooseShape,
+    PlaceShape,
+    SeasonScoring,
 }
 
 #[derive(Event)]
@@ -89,9 +150,34 @@ fn main() {
             }),
             FramepacePlugin,
             MeshPickingPlugin,
+        ))
+        .add_plugins((
             resource_tracking::plugin,
+            hooks::plugin,
+            manifest::plugin,
             asset_manager::plugin,
             map::plugin,
+            map_picker::plugin,
+            placement::plugin,
+            gallery::plugin,
+            history::plugin,
+            hints::plugin,
+            palette::plugin,
+            board_text::plugin,
+            painting::plugin,
+        ))
+        .add_plugins((
+            rules::plugin,
+            season::plugin,
+            scoring::plugin,
+            coins::plugin,
+            monsters::plugin,
+            mountains::plugin,
+            ruins::plugin,
+            results::plugin,
+            crash::plugin,
+            watchdog::plugin,
+            framepace::plugin,
         ))
         .insert_resource(SpritePickingSettings {
             require_markers: false,
@@ -103,33 +189,89 @@ fn main() {
         })
         .add_event::<SnapSelectedChoiceToCell>()
         .insert_resource(WorldPosition::default())
+        .insert_resource(ChoiceDim(0.4))
+        .insert_resource(GameSetup::random())
+        .insert_resource(GameRng(StdRng::seed_from_u64(0)))
+        .insert_resource(EdictRng(StdRng::seed_from_u64(EDICT_SALT)))
+        .add_observer(reopen_choices)
         .init_state::<AppState>()
-        .add_systems(OnEnter(AppState::InGame), (setup, spawn_random_tasks))
+        .add_sub_state::<TurnPhase>()
+        .enable_state_scoped_entities::<AppState>()
+        .add_systems(Startup, spawn_camera)
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (seed_game_rng, setup, spawn_random_tasks).chain(),
+        )
         .add_systems(PreUpdate, set_world_position)
         .add_systems(
             Update,
             (
-                spawn_random_tasks.run_if(input_just_pressed(KeyCode::Enter)),
-                draw_card.run_if(
-                    input_just_pressed(KeyCode::Space).or(on_event::<SelectedChoicePlaced>),
-                ),
-                position_selected_choice
+                (forget_edicts, spawn_random_tasks)
+                    .chain()
+                    .run_if(input_just_pressed(KeyCode::Enter)),
+                cycle_choice_dim.run_if(input_just_pressed(KeyCode::F5)),
+                (
+                    end_season_when_time_is_up,
+                    draw_card.run_if(season_in_progress),
+                )
+                    .chain()
+                    .in_set(GameSet::Draw)
+                    .run_if(in_state(TurnPhase::DrawCard)),
+                // the next card is only drawn once a shape of the current one is on the map
+                finish_turn.run_if(on_event::<SelectedChoicePlaced>),
+                (
+                    position_selected_choice
+                        .after(snap_selected_choice_to_cell)
+                        .run_if(not(is_inside_grid)),
+                    rotate_selected_choice.before(snap_selected_choice_to_cell),
+                    flip_selected_choice.before(snap_selected_choice_to_cell),
+                )
+                    .run_if(in_state(TurnPhase::PlaceShape)),
+                (create_choices, interactions)
+                    .chain()
+                    .run_if(in_state(TurnPhase::ChooseShape)),
+                preview_hovered_choice
                     .after(interactions)
-                    .after(snap_selected_choice_to_cell)
-                    .run_if(not(is_inside_grid)),
-                rotate_selected_choice.before(snap_selected_choice_to_cell),
-                flip_selected_choice.before(snap_selected_choice_to_cell),
-                create_choices,
-                interactions,
+                    .run_if(|hardcore: Res<Hardcore>| !hardcore.enabled),
             )
                 .run_if(in_state(AppState::InGame)),
         )
         .run();
 }
 
-fn setup(mut commands: Commands, card_fronts: Res<CardFronts>, card_backs: Res<CardBacks>) {
+// resources holding the progress of a game start over with every new game
+fn reset_resource<R: Resource + Default>(mut commands: Commands) {
+    commands.insert_resource(R::default());
+}
+
+impl GameSetup {
+    fn random() -> Self {
+        Self {
+            seed: random(),
+            edicts: Vec::new(),
+        }
+    }
+}
+
+fn seed_game_rng(
+    game_setup: Res<GameSetup>,
+    mut game_rng: ResMut<GameRng>,
+    mut edict_rng: ResMut<EdictRng>,
+) {
+    game_rng.0 = StdRng::seed_from_u64(game_setup.seed);
+    edict_rng.0 = StdRng::seed_from_u64(game_setup.seed ^ EDICT_SALT);
+}
+
+fn spawn_camera(mut commands: Commands) {
     commands.spawn((Camera2d, MainCamera));
+}
 
+fn setup(
+    mut commands: Commands,
+    card_fronts: Res<CardFronts>,
+    card_backs: Res<CardBacks>,
+    mut game_rng: ResMut<GameRng>,
+) {
     let mut drawable_cards = card_fronts
         .iter()
         .filter_map(|(card, handle)| match card {
@@ -140,10 +282,13 @@ fn setup(mut commands: Commands, card_fronts: Res<CardFronts>, card_backs: Res<C
 
     let mut deck_cards = Vec::new();
     let deck_position = Vec3::new(540.0, 240.0, 2.0);
-    drawable_cards.shuffle(&mut rng());
+    // sorted first, so the same seed deals the same deck in every run
+    drawable_cards.sort_by_key(|(card, _)| format!("{card:?}"));
+    drawable_cards.shuffle(&mut game_rng.0);
     for (card, handle) in drawable_cards.iter().skip(1).cloned() {
         let exploration_card = commands.spawn((
             card.clone(),
+            StateScoped(AppState::InGame),
             Sprite {
                 image: handle.clone(),
                 custom_size: Some(Vec2::new(150.0, 200.0)),
@@ -155,6 +300,7 @@ fn setup(mut commands: Commands, card_fronts: Res<CardFronts>, card_backs: Res<C
     }
     commands.spawn((
         TopOfDeck,
+        StateScoped(AppState::InGame),
         Sprite {
             image: card_backs.exploration.clone(),
             custom_size: Some(Vec2::new(150.0, 200.0)),
@@ -164,6 +310,7 @@ fn setup(mut commands: Commands, card_fronts: Res<CardFronts>, card_backs: Res<C
     ));
     commands.spawn((
         BottomOfDiscardPile,
+        StateScoped(AppState::InGame),
         Sprite {
             image: card_backs.exploration.clone(),
             custom_size: Some(Vec2::new(150.0, 200.0)),
@@ -175,22 +322,25 @@ fn setup(mut commands: Commands, card_fronts: Res<CardFronts>, card_backs: Res<C
 
     let (first_card, handle) = drawable_cards.first().expect("cards").clone();
     let drawn_card = commands
-        .spawn((first_card.clone(), Sprite::from_image(handle.clone())))
+        .spawn((
+            first_card.clone(),
+            Sprite::from_image(handle.clone()),
+            StateScoped(AppState::InGame),
+        ))
         .id();
-    commands.spawn(DrawnCard(drawn_card));
+    commands.spawn((DrawnCard(drawn_card), StateScoped(AppState::InGame)));
 
-    commands.spawn(Deck(deck_cards));
-    commands.spawn(DiscardPile(Vec::new()));
+    commands.spawn((Deck(deck_cards), StateScoped(AppState::InGame)));
+    commands.spawn((DiscardPile(Vec::new()), StateScoped(AppState::InGame)));
 
-    for (index, (_, scroll)) in card_fronts
-        .iter()
-        .filter(|(card, _)| matches!(card, Card::Scroll(_)))
-        .enumerate()
-    {
+    // in letter order, so the slot of a scroll matches the letter printed on it
+    for (index, scroll) in cards::Scroll::iter().enumerate() {
         commands.spawn((
             Scroll,
+            EdictSlot(index),
+            StateScoped(AppState::InGame),
             Sprite {
-                image: scroll.clone(),
+                image: card_fronts[&Card::Scroll(scroll)].clone(),
                 custom_size: Some(Vec2::new(100.0, 133.3)),
                 ..default()
             },
@@ -199,35 +349,61 @@ fn setup(mut commands: Commands, card_fronts: Res<CardFronts>, card_backs: Res<C
     }
 }
 
+fn forget_edicts(mut game_setup: ResMut<GameSetup>) {
+    game_setup.edicts.clear();
+}
+
 fn spawn_random_tasks(
     mut commands: Commands,
     tasks: Query<Entity, With<Scoring>>,
     card_fronts: Res<CardFronts>,
+    (mut game_setup, mut edict_rng): (ResMut<GameSetup>, ResMut<EdictRng>),
 ) {
     for task in tasks.iter() {
         commands.entity(task).despawn();
     }
-    let mut scoring_cards = card_fronts
+    // a rematch deals the edicts of the last game again
+    if game_setup.edicts.is_empty() {
+        game_setup.edicts = random_edicts(&card_fronts, &mut edict_rng.0);
+    }
+
+    game_setup
+        .edicts
         .iter()
-        .filter_map(|(card, handle)| match card {
-            Card::Scoring(scoring) => Some((scoring, handle)),
+        .enumerate()
+        .for_each(|(index, scoring)| {
+            commands.spawn((
+                scoring.clone(),
+                EdictSlot(index),
+                StateScoped(AppState::InGame),
+                Sprite {
+                    image: card_fronts[&Card::Scoring(scoring.clone())].clone(),
+                    custom_size: Some(Vec2::new(100.0, 133.3)),
+                    ..default()
+                },
+                Transform::from_translation(Vec3::new(index as f32 * 110.0 + 240.0, -270.0, 2.0)),
+            ));
+        });
+}
+
+fn random_edicts(card_fronts: &CardFronts, rng: &mut StdRng) -> Vec<Scoring> {
+    let mut scoring_cards = card_fronts
+        .keys()
+        .filter_map(|card| match card {
+            Card::Scoring(scoring) => Some(scoring.clone()),
             _ => None,
         })
         .collect::<Vec<_>>();
-    scoring_cards.shuffle(&mut rng());
+    scoring_cards.sort_by_key(|card| format!("{card:?}"));
+    scoring_cards.shuffle(rng);
 
     macro_rules! pick {
         ($match_cond:pat) => {
             scoring_cards
                 .iter()
-                .find_map(|(card, handle)| {
-                    if matches!(card, $match_cond) {
-                        Some((card.clone(), handle.clone()))
-                    } else {
-                        None
-                    }
-                })
+                .find(|card| matches!(card, $match_cond))
                 .expect("scoring card with given condition")
+                .clone()
         };
     }
 
@@ -237,22 +413,8 @@ fn spawn_random_tasks(
         pick!(Scoring::House(_)),
         pick!(Scoring::Shape(_)),
     ];
-    random_scoring.shuffle(&mut rng());
-
+    random_scoring.shuffle(rng);
     random_scoring
-        .into_iter()
-        .enumerate()
-        .for_each(|(index, (scoring, handle))| {
-            commands.spawn((
-                scoring.clone(),
-                Sprite {
-                    image: handle.clone(),
-                    custom_size: Some(Vec2::new(100.0, 133.3)),
-                    ..default()
-                },
-                Transform::from_translation(Vec3::new(index as f32 * 110.0 + 240.0, -270.0, 2.0)),
-            ));
-        });
 }
 
 fn draw_card(
@@ -262,6 +424,7 @@ fn draw_card(
     mut cards: Query<(&mut Transform, &mut Sprite), With<DrawableCard>>,
     mut visibility: Query<&mut Visibility, (With<DrawableCard>, Without<TopOfDeck>)>,
     mut top_of_deck: Single<&mut Visibility, (With<TopOfDeck>, Without<DrawableCard>)>,
+    (mut next_phase, mut game_rng): (ResMut<NextState<TurnPhase>>, ResMut<GameRng>),
 ) {
     let deck = &mut deck.0;
     if deck.is_empty() {
@@ -269,7 +432,7 @@ fn draw_card(
             .get_mut(*discard_pile.0.last().expect("cards"))
             .expect("visibility") = Visibility::Hidden;
         deck.extend(discard_pile.0.drain(..));
-        deck.shuffle(&mut rng());
+        deck.shuffle(&mut game_rng.0);
         info!("shuffled");
         **top_of_deck = Visibility::Inherited;
         return;
@@ -298,6 +461,11 @@ fn draw_card(
     if deck.is_empty() {
         **top_of_deck = Visibility::Hidden;
     }
+    next_phase.set(TurnPhase::ChooseShape);
+}
+
+fn finish_turn(mut next_phase: ResMut<NextState<TurnPhase>>) {
+    next_phase.set(TurnPhase::DrawCard);
 }
 
 fn create_choices(
@@ -306,26 +474,53 @@ fn create_choices(
     cards: Query<&DrawableCard>,
     mut commands: Commands,
     choice_ui: Option<Single<Entity, With<ChoiceUI>>>,
-    selected_choice: Option<Single<Entity, With<SelectedChoice>>>,
-    grid: Res<Grid>,
-    cells: Query<&Cell>,
+    (grid, cells): (Res<Grid>, Query<&Cell>),
+    (free_placement, hardcore): (Res<FreePlacement>, Res<Hardcore>),
+    (choice_dim, ruins_pending, mut next_phase): (
+        Res<ChoiceDim>,
+        Res<RuinsPending>,
+        ResMut<NextState<TurnPhase>>,
+    ),
 ) {
     if !drawn_card.is_changed() {
         return;
     }
     choice_ui.map(|ui| commands.entity(*ui).despawn());
-    selected_choice.map(|choice| commands.entity(*choice).despawn());
 
     let drawn_card = cards.get(drawn_card.0).expect("card");
-    let choices = &choices[drawn_card];
-    if choices.is_empty() {
+    if choices[drawn_card].is_empty() {
         return;
     }
     let cells = cells.iter().collect::<Vec<_>>();
+    let fits = |choice: &Choice, needs_ruins: bool| {
+        free_placement.enabled || fits_anywhere(choice, &cells, &grid, needs_ruins)
+    };
+    let mut offered_choices = choices[drawn_card]
+        .iter()
+        .map(|choice| (choice, fits(choice, ruins_pending.0), false))
+        .collect::<Vec<_>>();
+    if offered_choices.iter().all(|(_, fits, _)| !fits) {
+        // nothing fits in any orientation, so a single square may be drawn anywhere instead,
+        // monsters for an ambush and any other terrain for an exploration
+        let is_ambush = matches!(drawn_card, DrawableCard::Ambush(_));
+        offered_choices.extend(
+            choices[&DrawableCard::Exploration(Exploration::RiftLands17)]
+                .iter()
+                .filter(|choice| (choice.terrain == Terrain::Monster) == is_ambush)
+                .map(|choice| (choice, fits(choice, false), ruins_pending.0)),
+        );
+    }
+    // only a full map leaves no room for a single square, the card passes without a shape
+    if offered_choices.iter().all(|(_, fits, _)| !fits) {
+        info!("the map is full, nothing can be drawn for this card");
+        next_phase.set(TurnPhase::DrawCard);
+        return;
+    }
 
     commands
         .spawn((
             ChoiceUI,
+            StateScoped(AppState::InGame),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -334,11 +529,14 @@ fn create_choices(
                 column_gap: Val::Percent(5.0),
                 ..default()
             },
-            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.1)),
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, choice_dim.0)),
+            // keeps hovers and clicks away from the board underneath
+            FocusPolicy::Block,
+            Pickable::default(),
         ))
+        .observe(cancel_choices)
         .with_children(|parent| {
-            let mut only_disabled_choices = true;
-            choices.iter().for_each(|choice| {
+            for (choice, fits, ruins_waived) in offered_choices {
                 let size = choice.size(grid.cell_size);
                 let mut entity = parent.spawn((
                     Node {
@@ -363,18 +561,51 @@ fn create_choices(
                         }
                     )],
                 ));
-                if does_choice_fit_on_grid(cells.clone(), choice, &grid) {
-                    only_disabled_choices = false;
-                } else {
+                // hardcore leaves finding out that a choice has no legal placement to the board
+                if !fits && !hardcore.enabled {
                     entity.insert(DisabledChoice);
                 }
-            });
-            if only_disabled_choices {
-                info!("TODO: spawn alternatives");
+                if ruins_waived {
+                    entity.insert(RuinsWaived);
+                }
             }
         });
 }
 
+fn cycle_choice_dim(
+    mut choice_dim: ResMut<ChoiceDim>,
+    mut backdrop: Option<Single<&mut BackgroundColor, With<ChoiceUI>>>,
+) {
+    let levels = [0.1, 0.4, 0.7];
+    let index = levels
+        .iter()
+        .position(|level| *level == choice_dim.0)
+        .map_or(0, |index| (index + 1) % levels.len());
+    choice_dim.0 = levels[index];
+    if let Some(backdrop) = backdrop.as_mut() {
+        backdrop.0.set_alpha(choice_dim.0);
+    }
+}
+
+fn cancel_choices(trigger: Trigger<Pointer<Click>>, mut commands: Commands) {
+    // clicks on the choices bubble up to the backdrop
+    if trigger.event().target != trigger.target() {
+        return;
+    }
+    commands.entity(trigger.target()).despawn();
+}
+
+fn reopen_choices(
+    trigger: Trigger<Pointer<Click>>,
+    mut drawn_card: Single<&mut DrawnCard>,
+    choice_ui: Query<(), With<ChoiceUI>>,
+    selected_choice: Query<(), With<SelectedChoice>>,
+) {
+    if trigger.target() == drawn_card.0 && choice_ui.is_empty() && selected_choice.is_empty() {
+        drawn_card.set_changed();
+    }
+}
+
 // TODO: refactor to use Observables instead?
 fn interactions(
     mut commands: Commands,
@@ -384,35 +615,37 @@ fn interactions(
             &Choice,
             &mut BorderColor,
             Option<&DisabledChoice>,
+            Has<RuinsWaived>,
         ),
         (Changed<Interaction>, With<Button>),
     >,
     choice_ui: Single<Entity, With<ChoiceUI>>,
     player_map: Single<Entity, With<PlayerMap>>,
     grid: Res<Grid>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
 ) {
-    for (interaction, choice, mut color, disabled) in &mut interaction_query {
+    for (interaction, choice, mut color, disabled, ruins_waived) in &mut interaction_query {
         if disabled.is_some() {
             continue;
         }
         match interaction {
             Interaction::Pressed => {
                 commands.entity(*choice_ui).despawn();
-                commands.entity(*player_map).with_child((
-                    SelectedChoice {
-                        choice: choice.clone(),
-                        rotation: 0.0,
-                        valid_to_place: false,
-                        occupied_tiles: None,
-                        latest_hovered_cell: None,
-                    },
-                    Sprite {
-                        image: choice.image.clone(),
-                        custom_size: Some(choice.size(grid.cell_size)),
-                        ..default()
-                    },
-                    Transform::from_translation(Vec3::default().with_z(8.0)),
-                ));
+                next_phase.set(TurnPhase::PlaceShape);
+                commands.entity(*player_map).with_children(|parent| {
+                    let mut entity = parent.spawn((
+                        SelectedChoice::new(choice.clone()),
+                        Sprite {
+                            image: choice.image.clone(),
+                            custom_size: Some(choice.size(grid.cell_size)),
+                            ..default()
+                        },
+                        Transform::from_translation(Vec3::default().with_z(8.0)),
+                    ));
+                    if ruins_waived {
+                        entity.insert(RuinsWaived);
+                    }
+                });
             }
             Interaction::Hovered => {
                 color.0 = Color::srgb_u8(150, 150, 150);
@@ -424,6 +657,43 @@ fn interactions(
     }
 }
 
+fn preview_hovered_choice(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &Choice, Has<DisabledChoice>), Changed<Interaction>>,
+    choice_ui: Query<(), With<ChoiceUI>>,
+    ghost: Option<Single<Entity, With<GhostChoice>>>,
+    last_hovered_cell: Res<LastHoveredCell>,
+    cells: Query<&GlobalTransform, With<Cell>>,
+    grid: Res<Grid>,
+) {
+    if interaction_query.is_empty() && !choice_ui.is_empty() {
+        return;
+    }
+    if let Some(ghost) = ghost {
+        commands.entity(*ghost).despawn();
+    }
+    let Some(cell) = last_hovered_cell.0.and_then(|cell| cells.get(cell).ok()) else {
+        return;
+    };
+    for (_, choice, _) in interaction_query
+        .iter()
+        .filter(|(interaction, _, disabled)| **interaction == Interaction::Hovered && !disabled)
+    {
+        commands.spawn((
+            GhostChoice,
+            StateScoped(AppState::InGame),
+            Sprite {
+                image: choice.image.clone(),
+                custom_size: Some(choice.size(grid.cell_size)),
+                color: Color::srgba(1.0, 1.0, 1.0, 0.35),
+                ..default()
+            },
+            Pickable::IGNORE,
+            Transform::from_translation(cell.translation().with_z(7.0)).with_scale(cell.scale()),
+        ));
+    }
+}
+
 fn set_world_position(
     mut world_position: ResMut<WorldPosition>,
     window: Single<&Window, With<PrimaryWindow>>,
@@ -449,6 +719,7 @@ fn position_selected_choice(
     selected_choice.0.translation.y =
         (world_position.y - player_map.translation.y) / player_map.scale.y;
     selected_choice.1.latest_hovered_cell = None;
+    selected_choice.1.pose.anchor = None;
     selected_choice.1.occupied_tiles = None;
     selected_choice.2.color = Color::WHITE;
     selected_choice.1.valid_to_place = false;
@@ -459,12 +730,21 @@ fn rotate_selected_choice(
     mut selected_choice: Single<(&mut Transform, &mut SelectedChoice)>,
     mut mouse_wheel_events: EventReader<MouseWheel>,
 ) {
+    let (transform, selected_choice) = &mut *selected_choice;
     for event in mouse_wheel_events.read() {
-        selected_choice.1.rotation =
-            (selected_choice.1.rotation + 90.0 * event.y.signum() + 360.0) % 360.0;
-        selected_choice.0.rotation = Quat::from_rotation_z(selected_choice.1.rotation.to_radians());
+        let turn = if event.y.is_sign_negative() { 3 } else { 1 };
+        let orientation = selected_choice.pose.orientation;
+        let shape = oriented(&selected_choice.choice, orientation);
+        // symmetric shapes skip the turns that would leave them lying the same way
+        let Some(orientation) = (1..4)
+            .map(|times| orientation.turned(turn * times))
+            .find(|orientation| oriented(&selected_choice.choice, *orientation) != shape)
+        else {
+            continue;
+        };
+        selected_choice.pose.orientation = orientation;
+        transform.rotation = orientation.to_quat();
         selected_choice
-            .1
             .latest_hovered_cell
             .map(|cell| commands.send_event(SnapSelectedChoiceToCell(cell)));
     }
@@ -472,105 +752,128 @@ fn rotate_selected_choice(
 
 fn flip_selected_choice(
     mut commands: Commands,
-    mut selected_choice: Single<(&mut Sprite, &mut SelectedChoice)>,
+    selected_choice: Single<(&mut Sprite, &mut Transform, &mut SelectedChoice)>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
+    let (mut sprite, mut transform, mut selected_choice) = selected_choice.into_inner();
     let mut flipped = false;
-    if keyboard.just_pressed(KeyCode::KeyH) {
-        selected_choice.0.flip_x = !selected_choice.0.flip_x;
+    // F mirrors like H, the key most players reach for first
+    let orientation = &mut selected_choice.pose.orientation;
+    if keyboard.any_just_pressed([KeyCode::KeyH, KeyCode::KeyF]) {
+        *orientation = orientation.flipped_horizontally();
         flipped = true;
     }
     if keyboard.just_pressed(KeyCode::KeyV) {
-        selected_choice.0.flip_y = !selected_choice.0.flip_y;
+        *orientation = orientation.flipped_vertically();
         flipped = true;
     }
     if flipped {
+        sprite.flip_x = orientation.flipped;
+        transform.rotation = orientation.to_quat();
         selected_choice
-            .1
             .latest_hovered_cell
             .map(|cell| commands.send_event(SnapSelectedChoiceToCell(cell)));
     }
 }
 
-fn does_choice_fit_on_grid(cells: Vec<&Cell>, choice: &Choice, grid: &Grid) -> bool {
-    let empty_cells = cells
-        .iter()
-        .filter(|cell| cell.terrain == Terrain::None)
-        .map(|cell| cell.index)
-        .collect::<Vec<_>>();
-    let width = grid.dimension.0 - 1;
-    let height = grid.dimension.1 - 1;
-    // same as rotating normal all 4 directions and any flipped version all 4 directions
-    // TODO: calculate 8 orientations of tile instead (as a set to make it potentially less)
-    let mut configurations = vec![
-        empty_cells.clone(),
-        empty_cells.iter().map(|(x, y)| (width - *x, *y)).collect(),
-        empty_cells.iter().map(|(x, y)| (*x, height - *y)).collect(),
-        rotate_grid(&empty_cells, (width, height), 180),
-        rotate_grid(&empty_cells, (width, height), 270),
-    ];
-    configurations.extend(
-        (0..3)
-            .map(|i| rotate_grid(&configurations[i], (width, height), 90))
-            .collect::<Vec<_>>(),
-    );
-
-    let tiles = &choice.tiles;
-    let reference = (tiles[0].0 as isize, tiles[0].1 as isize);
-    for configuration in configurations.iter() {
-        let empty_cells = configuration.iter().collect::<HashSet<_>>();
-        'outer: for empty_cell in configuration {
-            let empty_cell = (empty_cell.0 as isize, empty_cell.1 as isize);
-            for (row, column) in tiles {
-                let (projected_row, projected_column) =
-                    (reference.0 - *row as isize, reference.1 - *column as isize);
-                let projected_cell = (
-                    projected_row + empty_cell.0,
-                    projected_column + empty_cell.1,
-                );
-                if projected_cell.0 < 0
-                    || projected_cell.0 >= grid.dimension.0 as isize
-                    || projected_cell.1 < 0
-                    || projected_cell.1 >= grid.dimension.1 as isize
-                    || !empty_cells
-                        .contains(&(projected_cell.0 as usize, projected_cell.1 as usize))
-                {
-                    continue 'outer;
-                }
-            }
-            return true;
+fn mark_disabled_choices(mut world: DeferredWorld, context: HookContext) {
+    let images = world
+        .get::<Children>(context.entity)
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    for image in images {
+        if let Some(mut image) = world.get_mut::<ImageNode>(image) {
+            image.color = Color::srgba(0.4, 0.4, 0.4, 0.6);
         }
     }
-    false
+    world
+        .commands()
+        .entity(context.entity)
+        .insert((BackgroundColor(Color::BLACK), BorderColor(Color::BLACK)))
+        .with_child((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(-16.0),
+                right: Val::Px(-16.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(8.0)),
+            BackgroundColor(Color::srgb_u8(200, 50, 40)),
+            children![(
+                Text::new("! no legal placement"),
+                TextFont::from_font_size(14.0),
+            )],
+        ));
 }
 
-fn rotate_grid(
-    cells: &Vec<(usize, usize)>,
-    (width, height): (usize, usize),
-    rotation: usize,
-) -> Vec<(usize, usize)> {
-    let mut rotated_cells = Vec::with_capacity(cells.len());
-    if rotation == 90 {
-        for (x, y) in cells {
-            rotated_cells.push((*y, height - *x));
-        }
-    } else if rotation == 180 {
-        for (x, y) in cells {
-            rotated_cells.push((width - *x, height - *y));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::collections::HashMap;
+
+    fn card_fronts() -> CardFronts {
+        CardFronts(HashMap::from_iter(
+            Card::get_paths()
+                .into_iter()
+                .map(|(card, _)| (card, Handle::default())),
+        ))
+    }
+
+    fn card_backs() -> CardBacks {
+        CardBacks {
+            exploration: Handle::default(),
+            season: Handle::default(),
+            farm: Handle::default(),
+            house: Handle::default(),
+            shape: Handle::default(),
+            tree: Handle::default(),
         }
-    } else if rotation == 270 {
-        for (x, y) in cells {
-            rotated_cells.push((width - *y, *x));
+    }
+
+    // runs the systems of a game start and draws cards like the turns would
+    fn play(game_setup: GameSetup, draws: usize) -> (Vec<DrawableCard>, GameSetup) {
+        let mut world = World::new();
+        world.insert_resource(card_fronts());
+        world.insert_resource(card_backs());
+        world.insert_resource(game_setup);
+        world.insert_resource(GameRng(StdRng::seed_from_u64(0)));
+        world.insert_resource(EdictRng(StdRng::seed_from_u64(EDICT_SALT)));
+        world.insert_resource(NextState::<TurnPhase>::Unchanged);
+        world.run_system_once(seed_game_rng).expect("seed");
+        world.run_system_once(setup).expect("setup");
+        world.run_system_once(spawn_random_tasks).expect("edicts");
+        let mut drawn_cards = Vec::new();
+        for _ in 0..draws {
+            world.run_system_once(draw_card).expect("draw");
+            let drawn_card = world
+                .query::<&DrawnCard>()
+                .single(&world)
+                .expect("drawn card")
+                .0;
+            drawn_cards.push(world.get::<DrawableCard>(drawn_card).expect("card").clone());
         }
-    } else {
-        unimplemented!();
+        let game_setup = world.remove_resource::<GameSetup>().expect("game setup");
+        (drawn_cards, game_setup)
     }
-    rotated_cells
-}
 
-fn mark_disabled_choices(mut world: DeferredWorld, context: HookContext) {
-    world
-        .commands()
-        .entity(context.entity)
-        .insert((BackgroundColor(Color::BLACK), BorderColor(Color::BLACK)));
+    #[test]
+    fn rematches_draw_the_same_cards_across_a_reshuffle() {
+        let deck_size = card_fronts()
+            .keys()
+            .filter(|card| matches!(card, Card::DrawableCard(_)))
+            .count();
+        let draws = 3 * deck_size;
+        let (first_game, game_setup) = play(
+            GameSetup {
+                seed: 29,
+                edicts: Vec::new(),
+            },
+            draws,
+        );
+        assert_eq!(game_setup.edicts.len(), 4);
+        let (rematch, _) = play(game_setup, draws);
+        assert_eq!(first_game, rematch);
+    }
 }
@@ -0,0 +1,58 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+
+pub fn plugin(app: &mut App) {
+    app.init_asset::<AssetManifest>()
+        .register_asset_loader(AssetManifestLoader);
+}
+
+// maps asset keys like `card.GreatRiver07` or `terrain.Forest` to paths relative
+// to the asset folder, one `key = path` pair per line
+#[derive(Asset, Debug, Default, TypePath)]
+pub struct AssetManifest(HashMap<String, String>);
+
+impl AssetManifest {
+    pub fn path(&self, key: &str, default: &str) -> String {
+        self.0
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| String::from(default))
+    }
+}
+
+struct AssetManifestLoader;
+
+impl AssetLoader for AssetManifestLoader {
+    type Asset = AssetManifest;
+    type Settings = ();
+    type Error = Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let content =
+            String::from_utf8(bytes).map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+
+        Ok(AssetManifest(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .filter_map(|line| line.split_once('='))
+                .map(|(key, path)| (String::from(key.trim()), String::from(path.trim())))
+                .collect(),
+        ))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["manifest"]
+    }
+}
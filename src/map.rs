@@ -1,20 +1,63 @@
 use crate::asset_manager::{PlayerMaps, TerrainImages};
-use crate::terrain::Terrain;
-use crate::{AppState, SelectedChoice, SnapSelectedChoiceToCell, WorldPosition};
+use crate::cards::{DrawableCard, Scoring};
+use crate::placement::{ChoicePose, Orientation, Rotation, SelectedChoice};
+use crate::ruins::{RuinsPending, RuinsWaived};
+use crate::rules::{FreePlacement, Hardcore};
+use crate::scoring::{Board, EdictSlot, score_edict};
+use crate::season::Season;
+use crate::terrain::{Choice, Terrain};
+use crate::{
+    AppState, DrawnCard, SnapSelectedChoiceToCell, TurnPhase, WorldPosition, reset_resource,
+};
 use bevy::input::common_conditions::input_just_pressed;
 use bevy::prelude::*;
 use std::collections::{HashMap, HashSet};
+use strum::EnumIter;
 
 pub fn plugin(app: &mut App) {
     app.add_event::<SelectedChoicePlaced>()
-        .add_systems(OnEnter(AppState::InGame), setup)
+        .add_event::<PlaceSelectedChoice>()
+        .insert_resource(LastHoveredCell::default())
+        .insert_resource(CoordinateLabels::default())
+        .insert_resource(MapSide::default())
+        .insert_resource(ScoringPreview::default())
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (
+                setup,
+                reset_resource::<LastHoveredCell>,
+                reset_resource::<CoordinateLabels>,
+                reset_resource::<ScoringPreview>,
+            ),
+        )
         .add_systems(
             Update,
             (
                 (snap_selected_choice_to_cell, highlight_selected_choice)
                     .chain()
                     .run_if(on_event::<SnapSelectedChoiceToCell>),
-                place_selected_choice.run_if(input_just_pressed(MouseButton::Left)),
+                show_rotation_assist.after(highlight_selected_choice),
+                toggle_coordinate_labels
+                    .run_if(input_just_pressed(KeyCode::F6))
+                    .run_if(in_state(AppState::InGame)),
+                (
+                    request_placement.run_if(input_just_pressed(KeyCode::KeyP)),
+                    place_selected_choice.run_if(on_event::<PlaceSelectedChoice>),
+                )
+                    .chain()
+                    .run_if(in_state(TurnPhase::PlaceShape)),
+                (
+                    cycle_scoring_preview
+                        .run_if(input_just_pressed(KeyCode::KeyE))
+                        .run_if(|hardcore: Res<Hardcore>| !hardcore.enabled),
+                    show_scoring_preview.run_if(
+                        resource_changed::<ScoringPreview>
+                            .or(resource_changed::<Season>)
+                            .or(any_match_filter::<Changed<Cell>>),
+                    ),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::InGame)),
             ),
         );
 }
@@ -34,10 +77,117 @@ pub struct Grid {
 pub struct Cell {
     pub terrain: Terrain,
     pub index: (usize, usize),
+    pub feature: CellFeature,
+}
+
+// printed on the map and unaffected by the terrain drawn over it
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CellFeature {
+    #[default]
+    None,
+    Ruins,
+    // torn out of the map, it counts as filled and can never be drawn on
+    Wasteland,
+}
+
+// the side of the player map the next game is played on
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, PartialEq, Resource)]
+pub enum MapSide {
+    #[default]
+    A,
+    B,
+}
+
+// what is printed on a side of the player map as (row, column) indices
+#[derive(Clone, Copy, Debug)]
+pub struct MapLayout {
+    pub mountains: &'static [(usize, usize)],
+    pub ruins: &'static [(usize, usize)],
+    pub wastelands: &'static [(usize, usize)],
+}
+
+impl MapSide {
+    pub fn layout(&self) -> MapLayout {
+        match self {
+            MapSide::A => MapLayout {
+                mountains: &[(1, 3), (2, 8), (5, 5), (8, 2), (9, 7)],
+                ruins: &[(1, 5), (2, 1), (2, 9), (8, 1), (8, 9), (9, 5)],
+                wastelands: &[],
+            },
+            MapSide::B => MapLayout {
+                mountains: &[(1, 8), (2, 3), (7, 5), (8, 9), (9, 2)],
+                ruins: &[(1, 6), (2, 2), (4, 6), (6, 1), (7, 8), (9, 3)],
+                wastelands: &[(3, 5), (4, 4), (4, 5), (5, 4), (5, 5), (5, 6), (6, 5)],
+            },
+        }
+    }
+
+    // the map image and the area of the grid on it
+    pub fn image(&self, player_maps: &PlayerMaps) -> (Handle<Image>, Rect) {
+        match self {
+            MapSide::A => (player_maps.side_a.clone(), player_maps.side_a_rect),
+            MapSide::B => (player_maps.side_b.clone(), player_maps.side_b_rect),
+        }
+    }
+}
+
+// for tests of code that only works with cell indices
+#[cfg(test)]
+impl Grid {
+    pub fn with_dimension(dimension: (usize, usize)) -> Self {
+        Self {
+            cell_size: Vec2::ONE,
+            dimension,
+            top_left_cell_offset: Vec2::ZERO,
+            scale: 1.0,
+        }
+    }
+}
+
+impl Cell {
+    // neither drawn on nor torn out of the map
+    pub fn is_empty(&self) -> bool {
+        self.terrain == Terrain::None && self.feature != CellFeature::Wasteland
+    }
 }
 
+#[derive(Component)]
+struct RotationAssist;
+
+#[derive(Component)]
+struct CellHover;
+
+#[derive(Debug, Default, Resource)]
+pub struct CoordinateLabels {
+    pub enabled: bool,
+}
+
+#[derive(Component)]
+struct CoordinateLabel;
+
+#[derive(Debug, Default, Resource)]
+pub struct LastHoveredCell(pub Option<Entity>);
+
+// one of the edicts scored at the end of the current season, previewed on the board
+#[derive(Debug, Default, Resource)]
+pub struct ScoringPreview {
+    pub slot: Option<EdictSlot>,
+}
+
+#[derive(Component)]
+struct ScoringPreviewMarker;
+
+// sent by a click on the board or the place key, clicks on the UI never reach the board
 #[derive(Event)]
-pub struct SelectedChoicePlaced;
+struct PlaceSelectedChoice;
+
+#[derive(Event)]
+pub struct SelectedChoicePlaced {
+    pub card: DrawableCard,
+    pub terrain: Terrain,
+    pub tiles: Vec<(usize, usize)>,
+    pub with_coin: bool,
+}
 
 trait ToVec2 {
     fn to_vec2(&self) -> Vec2;
@@ -74,54 +224,121 @@ pub fn is_inside_grid(
     rect.contains(**world_position)
 }
 
+// columns are lettered from A and rows numbered from 1, e.g. D7
+pub fn coordinate((row, column): (usize, usize)) -> String {
+    format!("{}{}", (b'A' + column as u8) as char, row + 1)
+}
+
+pub fn orthogonal_neighbours((row, column): (usize, usize)) -> Vec<(usize, usize)> {
+    let mut neighbours = vec![(row + 1, column), (row, column + 1)];
+    if row > 0 {
+        neighbours.push((row - 1, column));
+    }
+    if column > 0 {
+        neighbours.push((row, column - 1));
+    }
+    neighbours
+}
+
+// splits the given cells into groups connected through their sides, e.g. all
+// forests into the forest clusters of the board
+pub fn regions(indices: impl IntoIterator<Item = (usize, usize)>) -> Vec<HashSet<(usize, usize)>> {
+    let mut remaining = indices.into_iter().collect::<HashSet<_>>();
+    let mut regions = Vec::new();
+    while let Some(start) = remaining.iter().next().copied() {
+        remaining.remove(&start);
+        let mut region = HashSet::from([start]);
+        let mut open = vec![start];
+        while let Some(index) = open.pop() {
+            for neighbour in orthogonal_neighbours(index) {
+                if remaining.remove(&neighbour) {
+                    region.insert(neighbour);
+                    open.push(neighbour);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
 pub fn snap_selected_choice_to_cell(
     grid: Res<Grid>,
     cells: Query<&Cell>,
     mut event_reader: EventReader<SnapSelectedChoiceToCell>,
-    mut selected_choice: Single<(&mut Transform, &mut SelectedChoice, &Sprite)>,
+    selected_choice: Single<(&mut Transform, &mut SelectedChoice, &mut Sprite)>,
 ) {
+    let (mut transform, mut selected_choice, mut sprite) = selected_choice.into_inner();
     let cell = event_reader.read().next().expect("cell");
-    selected_choice.1.latest_hovered_cell = Some(cell.0);
+    selected_choice.latest_hovered_cell = Some(cell.0);
+    let cell = cells.get(cell.0).expect("cell");
+    selected_choice.pose.anchor = Some(cell.index);
 
-    let rotation = selected_choice.1.rotation.to_radians();
-    let cos = rotation.cos().round();
-    let sin = rotation.sin().round();
-    let rotation_factor = Vec2::new(cos - sin, cos + sin);
+    let pose = selected_choice.pose;
+    selected_choice.occupied_tiles = Some(
+        selected_choice
+            .choice
+            .tiles_at(pose.orientation, cell.index),
+    );
+    let z = transform.translation.z;
+    *transform = choice_transform(&selected_choice.choice, &pose, &grid);
+    transform.translation.z = z;
+    sprite.flip_x = pose.orientation.flipped;
 
-    let cell = cells.get(cell.0).expect("cell");
-    let choice_size = selected_choice.1.choice.size(grid.cell_size);
-    let reference_cell = (((choice_size / grid.cell_size).yx() - Vec2::X) / 2.0).floor();
-    let mut reference_cell_offset =
-        (reference_cell - ((choice_size / grid.cell_size).yx() - Vec2::ONE) / 2.0) * grid.cell_size;
-    let reference_cell = (reference_cell.x as isize, reference_cell.y as isize);
-
-    if cos == 0.0 {
-        reference_cell_offset = reference_cell_offset.yx();
-    }
-    selected_choice.0.translation = (grid.top_left_cell_offset
-        - reference_cell_offset.yx() * rotation_factor
-        + (cell.index.1, cell.index.0).to_vec2() * grid.cell_size.inverse_y())
-    .extend(selected_choice.0.translation.z);
-
-    let (row_offset, column_offset) = (
-        if reference_cell_offset.x == 0.0 {
-            0
-        } else {
-            reference_cell_offset.x.signum() as isize
-        },
-        if reference_cell_offset.y == 0.0 {
-            0
-        } else {
-            reference_cell_offset.y.signum() as isize
-        },
+    event_reader.clear();
+}
+
+// the transform of a choice sprite lying on the grid as described by the pose
+pub fn choice_transform(choice: &Choice, pose: &ChoicePose, grid: &Grid) -> Transform {
+    let anchor = pose.anchor.expect("a pose on the grid");
+    // cos - sin and cos + sin of the rotation
+    let rotation = pose.orientation.rotation;
+    let rotation_factor = match rotation {
+        Rotation::R0 => Vec2::new(1.0, 1.0),
+        Rotation::R90 => Vec2::new(-1.0, 1.0),
+        Rotation::R180 => Vec2::new(-1.0, -1.0),
+        Rotation::R270 => Vec2::new(1.0, -1.0),
+    };
+    let (_, (row_offset, column_offset)) = reference_cell(choice, rotation);
+    // the offset is counted in half cells
+    let reference_cell_offset =
+        Vec2::new(row_offset as f32, column_offset as f32) * grid.cell_size / 2.0;
+    let translation = grid.top_left_cell_offset - reference_cell_offset.yx() * rotation_factor
+        + (anchor.1, anchor.0).to_vec2() * grid.cell_size.inverse_y();
+    Transform::from_translation(translation.extend(0.0)).with_rotation(pose.orientation.to_quat())
+}
+
+// the tile snapped to the hovered cell and how far it sits from the center
+// of the choice in half cells, which is -1, 0 or 1 on each axis
+fn reference_cell(choice: &Choice, rotation: Rotation) -> ((isize, isize), (isize, isize)) {
+    let (rows, columns) = choice.dimension();
+    let (rows, columns) = (rows as isize, columns as isize);
+    let reference_cell = ((rows - 1) / 2, columns / 2);
+    let mut reference_cell_offset = (
+        2 * reference_cell.0 - (rows - 1),
+        2 * reference_cell.1 - (columns - 1),
     );
-    let mag = row_offset.abs() + column_offset.abs();
-    let row_mag = if selected_choice.2.flip_x { mag } else { 0 };
-    let column_mag = if selected_choice.2.flip_y { mag } else { 0 };
+    if matches!(rotation, Rotation::R90 | Rotation::R270) {
+        reference_cell_offset = (reference_cell_offset.1, reference_cell_offset.0);
+    }
+    (reference_cell, reference_cell_offset)
+}
 
-    let shifted_tiles = selected_choice
-        .1
-        .choice
+// the rotation math behind the orientation tables of a choice, look the tiles up with
+// `Choice::tiles_at` instead
+pub fn occupied_tiles(
+    choice: &Choice,
+    Orientation { rotation, flipped }: Orientation,
+    cell_index: (usize, usize),
+) -> Vec<(isize, isize)> {
+    let (reference_cell, (row_offset, column_offset)) = reference_cell(choice, rotation);
+    let mag = if flipped {
+        row_offset.abs() + column_offset.abs()
+    } else {
+        0
+    };
+
+    choice
         .tiles
         .iter()
         .map(|(row, column)| {
@@ -129,55 +346,145 @@ pub fn snap_selected_choice_to_cell(
                 *row as isize - reference_cell.0,
                 *column as isize - reference_cell.1,
             );
-            if selected_choice.2.flip_x {
+            if flipped {
                 shifted.1 *= -1;
                 shifted.1 -= column_offset;
             }
-            if selected_choice.2.flip_y {
-                shifted.0 *= -1;
-                shifted.0 -= row_offset;
+            match rotation {
+                Rotation::R0 => shifted,
+                Rotation::R90 => (shifted.1 - mag, -shifted.0),
+                Rotation::R180 => (-shifted.0, -shifted.1),
+                Rotation::R270 => (-shifted.1 + mag, shifted.0),
             }
-            let shifted = if rotation.to_degrees() == 90.0 {
-                (shifted.1 - row_mag, -shifted.0 - column_mag)
-            } else if rotation.to_degrees() == 180.0 {
-                (-shifted.0, -shifted.1)
-            } else if rotation.to_degrees() == 270.0 {
-                (-shifted.1 + row_mag, shifted.0 + column_mag)
-            } else {
-                shifted
-            };
-            shifted
         })
-        .collect::<Vec<_>>();
+        .map(|(row, column)| (-row + cell_index.0 as isize, column + cell_index.1 as isize))
+        .collect()
+}
 
-    let occupied_tiles = shifted_tiles
-        .into_iter()
-        .map(|(row, column)| (-row + cell.index.0 as isize, column + cell.index.1 as isize))
-        .collect::<Vec<_>>();
+pub fn fits_on_grid(
+    tiles: &[(isize, isize)],
+    placed_cells: &HashSet<(isize, isize)>,
+    grid: &Grid,
+) -> bool {
+    tiles.iter().all(|(row, column)| {
+        *row >= 0
+            && *column >= 0
+            && *row < grid.dimension.0 as isize
+            && *column < grid.dimension.1 as isize
+            && !placed_cells.contains(&(*row, *column))
+    })
+}
+
+// tries every cell in every distinct rotation and mirroring, just like the selected choice can be moved
+pub fn fits_anywhere(choice: &Choice, cells: &[&Cell], grid: &Grid, needs_ruins: bool) -> bool {
+    let placed_cells = placed_cells(cells.iter().copied());
+    choice.distinct_orientations.iter().any(|orientation| {
+        cells.iter().any(|cell| {
+            let tiles = choice.tiles_at(*orientation, cell.index);
+            is_legal_placement(&tiles, cells, &placed_cells, grid, needs_ruins)
+        })
+    })
+}
 
-    selected_choice.1.occupied_tiles = Some(occupied_tiles);
+// the one check behind disabled choices, the highlight and the rotation assist
+pub fn is_legal_placement(
+    tiles: &[(isize, isize)],
+    cells: &[&Cell],
+    placed_cells: &HashSet<(isize, isize)>,
+    grid: &Grid,
+    needs_ruins: bool,
+) -> bool {
+    fits_on_grid(tiles, placed_cells, grid)
+        && (!needs_ruins || covers_ruins(tiles, cells.iter().copied()))
+}
 
-    event_reader.clear();
+// free placement and the fallback squares are exempt from a pending ruins card
+fn needs_ruins(
+    ruins_pending: &RuinsPending,
+    free_placement: &FreePlacement,
+    ruins_waived: bool,
+) -> bool {
+    ruins_pending.0 && !free_placement.enabled && !ruins_waived
+}
+
+pub fn covers_ruins<'a>(
+    tiles: &[(isize, isize)],
+    cells: impl IntoIterator<Item = &'a Cell>,
+) -> bool {
+    cells.into_iter().any(|cell| {
+        cell.feature == CellFeature::Ruins
+            && tiles.contains(&(cell.index.0 as isize, cell.index.1 as isize))
+    })
+}
+
+pub fn placed_cells<'a>(cells: impl IntoIterator<Item = &'a Cell>) -> HashSet<(isize, isize)> {
+    cells
+        .into_iter()
+        .filter(|cell| !cell.is_empty())
+        .map(|cell| (cell.index.0 as isize, cell.index.1 as isize))
+        .collect()
 }
 
-fn trigger_grid_snapping(trigger: Trigger<Pointer<Over>>, mut commands: Commands) {
+fn trigger_grid_snapping(
+    trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    mut last_hovered_cell: ResMut<LastHoveredCell>,
+) {
+    last_hovered_cell.0 = Some(trigger.target());
     commands.send_event(SnapSelectedChoiceToCell(trigger.target()));
 }
 
+fn show_cell_hover(
+    trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    hovers: Query<Entity, With<CellHover>>,
+    grid: Res<Grid>,
+) {
+    for hover in hovers.iter() {
+        commands.entity(hover).despawn();
+    }
+    // brightens the cell slightly, the coin of a mountain stays on top
+    commands.entity(trigger.target()).with_child((
+        CellHover,
+        Sprite {
+            color: Color::srgba(1.0, 1.0, 0.9, 0.15),
+            custom_size: Some(grid.cell_size * 0.94),
+            ..default()
+        },
+        Pickable::IGNORE,
+        Transform::from_xyz(0.0, 0.0, 0.5),
+    ));
+}
+
+fn hide_cell_hover(
+    trigger: Trigger<Pointer<Out>>,
+    mut commands: Commands,
+    hovers: Query<(Entity, &ChildOf), With<CellHover>>,
+) {
+    for (hover, _) in hovers
+        .iter()
+        .filter(|(_, child_of)| child_of.parent() == trigger.target())
+    {
+        commands.entity(hover).despawn();
+    }
+}
+
 fn setup(
     mut commands: Commands,
     images: Res<Assets<Image>>,
     player_maps: Res<PlayerMaps>,
     window: Single<&Window>,
     terrain_images: Res<TerrainImages>,
+    map_side: Res<MapSide>,
 ) {
-    let map_image = images.get(player_maps.side_a.id()).expect("player map");
+    let (map_handle, map_area) = map_side.image(&player_maps);
+    let layout = map_side.layout();
+    let map_image = images.get(map_handle.id()).expect("player map");
     let map_size = map_image.size_f32();
     let map_dimension = (11, 11);
     let map_scale = window.height() / map_size.y;
     let map_pos = Vec2::new((map_size.x * map_scale - window.width()) / 2.0, 0.0);
 
-    let map_area = player_maps.side_a_rect;
     let cell_size = map_area.size() / map_dimension.to_vec2();
     let top_left_cell_offset = (map_area.min + (cell_size - map_size) / 2.0).inverse_y();
 
@@ -190,22 +497,32 @@ fn setup(
     let map_entity = commands
         .spawn((
             PlayerMap,
-            Sprite::from_image(player_maps.side_a.clone()),
+            StateScoped(AppState::InGame),
+            Sprite::from_image(map_handle),
             Transform::from_translation(map_pos.extend(-2.0))
                 .with_scale(Vec2::splat(map_scale).extend(1.0)),
         ))
+        .observe(place_on_click)
         .id();
 
-    let mountains = vec![(1, 3), (2, 8), (5, 5), (8, 2), (9, 7)];
     let mut observer = Observer::new(trigger_grid_snapping);
+    let mut hover_observer = Observer::new(show_cell_hover);
+    let mut out_observer = Observer::new(hide_cell_hover);
     for column in 0..map_dimension.0 {
         for row in 0..map_dimension.1 {
             let index = (row, column);
-            let terrain = if mountains.contains(&index) {
+            let terrain = if layout.mountains.contains(&index) {
                 Terrain::Mountain
             } else {
                 Terrain::default()
             };
+            let feature = if layout.ruins.contains(&index) {
+                CellFeature::Ruins
+            } else if layout.wastelands.contains(&index) {
+                CellFeature::Wasteland
+            } else {
+                CellFeature::None
+            };
             let cell_entity = commands
                 .spawn((
                     Sprite {
@@ -221,57 +538,135 @@ fn setup(
                         (top_left_cell_offset + cell_size * (column, row).to_vec2().inverse_y())
                             .extend(1.0),
                     ),
-                    Cell { terrain, index },
+                    Cell {
+                        terrain,
+                        index,
+                        feature,
+                    },
                 ))
                 .id();
             observer.watch_entity(cell_entity);
+            hover_observer.watch_entity(cell_entity);
+            out_observer.watch_entity(cell_entity);
             commands.entity(map_entity).add_child(cell_entity);
         }
     }
-    commands.spawn(observer);
+    commands.spawn((observer, StateScoped(AppState::InGame)));
+    commands.spawn((hover_observer, StateScoped(AppState::InGame)));
+    commands.spawn((out_observer, StateScoped(AppState::InGame)));
 }
 
 fn highlight_selected_choice(
-    selected_choice: Single<(&mut Sprite, &mut SelectedChoice)>,
+    selected_choice: Single<(&mut Sprite, &mut SelectedChoice, Has<RuinsWaived>)>,
     cells: Query<&Cell>,
     grid: Res<Grid>,
+    ruins_pending: Res<RuinsPending>,
+    free_placement: Res<FreePlacement>,
 ) {
-    let (mut sprite, mut selected_choice) = selected_choice.into_inner();
+    let (mut sprite, mut selected_choice, ruins_waived) = selected_choice.into_inner();
     sprite.color = Color::WHITE;
     let Some(occupied_tiles) = selected_choice.occupied_tiles.as_ref() else {
         return;
     };
 
-    let outside_grid = occupied_tiles.iter().cloned().any(|(row, column)| {
-        row < 0
-            || column < 0
-            || row >= grid.dimension.0 as isize
-            || column >= grid.dimension.1 as isize
-    });
+    let cells = cells.iter().collect::<Vec<_>>();
+    let needs_ruins = needs_ruins(&ruins_pending, &free_placement, ruins_waived);
+    if is_legal_placement(
+        occupied_tiles,
+        &cells,
+        &placed_cells(cells.iter().copied()),
+        &grid,
+        needs_ruins,
+    ) {
+        selected_choice.valid_to_place = true;
+    } else {
+        sprite.color = Color::srgba(1.0, 1.0, 1.0, 0.5);
+        selected_choice.valid_to_place = false;
+    }
+}
 
-    let placed_cells = cells
-        .iter()
-        .filter(|cell| cell.terrain != Terrain::None)
-        .map(|cell| (cell.index.0 as isize, cell.index.1 as isize))
-        .collect::<HashSet<_>>();
+fn show_rotation_assist(
+    mut commands: Commands,
+    selected_choice: Single<
+        (Entity, &SelectedChoice, &Transform, Has<RuinsWaived>),
+        Changed<SelectedChoice>,
+    >,
+    cells: Query<&Cell>,
+    grid: Res<Grid>,
+    assist: Option<Single<Entity, With<RotationAssist>>>,
+    (hardcore, free_placement, ruins_pending): (
+        Res<Hardcore>,
+        Res<FreePlacement>,
+        Res<RuinsPending>,
+    ),
+) {
+    let (entity, selected_choice, transform, ruins_waived) = *selected_choice;
+    if let Some(assist) = assist {
+        commands.entity(*assist).despawn();
+    }
+    if hardcore.enabled {
+        return;
+    }
+    let Some(cell) = selected_choice
+        .latest_hovered_cell
+        .and_then(|cell| cells.get(cell).ok())
+    else {
+        return;
+    };
+    if selected_choice.valid_to_place || selected_choice.occupied_tiles.is_none() {
+        return;
+    }
 
-    let colliding_with_cell = occupied_tiles
-        .iter()
-        .any(|tile| placed_cells.contains(tile));
+    let cells = cells.iter().collect::<Vec<_>>();
+    let placed_cells = placed_cells(cells.iter().copied());
+    let needs_ruins = needs_ruins(&ruins_pending, &free_placement, ruins_waived);
+    let fitting_turns = [(1, "+90"), (2, "180"), (3, "-90")]
+        .into_iter()
+        .filter(|(turn, _)| {
+            let tiles = selected_choice
+                .choice
+                .tiles_at(selected_choice.pose.orientation.turned(*turn), cell.index);
+            is_legal_placement(&tiles, &cells, &placed_cells, &grid, needs_ruins)
+        })
+        .map(|(_, label)| label)
+        .collect::<Vec<_>>();
+    if fitting_turns.is_empty() {
+        return;
+    }
 
-    if outside_grid || colliding_with_cell {
-        sprite.color = Color::srgba(1.0, 1.0, 1.0, 0.5);
-        selected_choice.valid_to_place = false;
-    } else {
-        selected_choice.valid_to_place = true;
+    // counter the rotation of the choice so the text stays upright above it
+    let offset = selected_choice.choice.size(grid.cell_size).max_element() / 2.0;
+    let inverse_rotation = transform.rotation.inverse();
+    commands.entity(entity).with_child((
+        RotationAssist,
+        Text2d::new(format!("fits rotated {}", fitting_turns.join(" / "))),
+        TextFont::from_font_size(28.0),
+        TextColor(Color::srgb_u8(250, 230, 120)),
+        Transform::from_translation(
+            inverse_rotation * Vec3::new(0.0, offset + grid.cell_size.y / 2.0, 1.0),
+        )
+        .with_rotation(inverse_rotation),
+    ));
+}
+
+// clicks on cells and on the selected choice bubble up to the map
+fn place_on_click(trigger: Trigger<Pointer<Click>>, mut commands: Commands) {
+    if trigger.button == PointerButton::Primary {
+        commands.send_event(PlaceSelectedChoice);
     }
 }
 
+fn request_placement(mut commands: Commands) {
+    commands.send_event(PlaceSelectedChoice);
+}
+
 fn place_selected_choice(
     mut commands: Commands,
     selected_choice: Single<&SelectedChoice>,
     cells: Query<(&mut Cell, &mut Sprite)>,
     terrain_images: Res<TerrainImages>,
+    drawn_card: Single<&DrawnCard>,
+    cards: Query<&DrawableCard>,
 ) {
     let selected_choice = selected_choice.into_inner();
     if !selected_choice.valid_to_place {
@@ -281,12 +676,297 @@ fn place_selected_choice(
         .into_iter()
         .map(|cell| (cell.0.index, cell))
         .collect::<HashMap<_, _>>();
-    for (row, column) in selected_choice.occupied_tiles.as_ref().expect("tiles") {
-        let cell = cells
-            .get_mut(&(*row as usize, *column as usize))
-            .expect("cell");
+    let tiles = selected_choice
+        .occupied_tiles
+        .as_ref()
+        .expect("tiles")
+        .iter()
+        .map(|(row, column)| (*row as usize, *column as usize))
+        .collect::<Vec<_>>();
+    for tile in tiles.iter() {
+        let cell = cells.get_mut(tile).expect("cell");
         cell.0.terrain = selected_choice.choice.terrain.clone();
         cell.1.image = terrain_images[&cell.0.terrain].clone();
     }
-    commands.send_event(SelectedChoicePlaced);
+    commands.send_event(SelectedChoicePlaced {
+        card: cards.get(drawn_card.0).expect("card").clone(),
+        terrain: selected_choice.choice.terrain.clone(),
+        tiles,
+        with_coin: selected_choice.choice.with_coin,
+    });
+}
+
+fn toggle_coordinate_labels(
+    mut commands: Commands,
+    mut coordinate_labels: ResMut<CoordinateLabels>,
+    labels: Query<Entity, With<CoordinateLabel>>,
+    player_map: Single<Entity, With<PlayerMap>>,
+    grid: Res<Grid>,
+) {
+    coordinate_labels.enabled = !coordinate_labels.enabled;
+    for label in labels.iter() {
+        commands.entity(label).despawn();
+    }
+    if !coordinate_labels.enabled {
+        return;
+    }
+    let cell_position = |row: f32, column: f32| {
+        (grid.top_left_cell_offset + grid.cell_size * Vec2::new(column, row).inverse_y())
+            .extend(1.0)
+    };
+    let (rows, columns) = grid.dimension;
+    let labels = (0..columns)
+        .map(|column| {
+            let text = coordinate((0, column));
+            (text[..1].to_string(), cell_position(-1.0, column as f32))
+        })
+        .chain((0..rows).map(|row| {
+            let text = coordinate((row, 0));
+            (text[1..].to_string(), cell_position(row as f32, -1.0))
+        }));
+    for (text, position) in labels {
+        commands.entity(*player_map).with_child((
+            CoordinateLabel,
+            Text2d::new(text),
+            TextFont::from_font_size(grid.cell_size.y * 0.5),
+            TextColor(Color::srgb_u8(60, 40, 20)),
+            Pickable::IGNORE,
+            Transform::from_translation(position),
+        ));
+    }
+}
+
+// off, then each edict of the season in turn
+fn cycle_scoring_preview(mut preview: ResMut<ScoringPreview>, season: Res<Season>) {
+    let slots = season.current.edict_slots();
+    preview.slot = match preview.slot {
+        None => Some(slots[0]),
+        Some(slot) if slot == slots[0] => Some(slots[1]),
+        Some(_) => None,
+    };
+}
+
+fn show_scoring_preview(
+    mut commands: Commands,
+    preview: Res<ScoringPreview>,
+    season: Res<Season>,
+    edicts: Query<(&Scoring, &EdictSlot)>,
+    cells: Query<(Entity, &Cell)>,
+    markers: Query<Entity, With<ScoringPreviewMarker>>,
+    grid: Res<Grid>,
+) {
+    for marker in markers.iter() {
+        commands.entity(marker).despawn();
+    }
+    // the preview ends with the season of its edict
+    let Some((edict, slot)) = preview
+        .slot
+        .filter(|slot| season.current.edict_slots().contains(slot))
+        .and_then(|slot| edicts.iter().find(|(_, edict_slot)| **edict_slot == slot))
+    else {
+        return;
+    };
+
+    let score = score_edict(
+        edict,
+        &Board::new(cells.iter().map(|(_, cell)| cell), grid.dimension),
+    );
+    for (entity, _) in cells
+        .iter()
+        .filter(|(_, cell)| score.cells.contains(&cell.index))
+    {
+        commands.entity(entity).with_child((
+            ScoringPreviewMarker,
+            Sprite {
+                color: Color::srgba(0.2, 0.7, 1.0, 0.45),
+                custom_size: Some(grid.cell_size),
+                ..default()
+            },
+            Pickable::IGNORE,
+            Transform::from_xyz(0.0, 0.0, 0.9),
+        ));
+    }
+    commands.spawn((
+        ScoringPreviewMarker,
+        StateScoped(AppState::InGame),
+        Text::new(format!(
+            "Edict {} would score {} points now",
+            slot.letter(),
+            score.points
+        )),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn sorted(regions: Vec<HashSet<(usize, usize)>>) -> Vec<Vec<(usize, usize)>> {
+        let mut regions = regions
+            .into_iter()
+            .map(|region| {
+                let mut region = region.into_iter().collect::<Vec<_>>();
+                region.sort();
+                region
+            })
+            .collect::<Vec<_>>();
+        regions.sort();
+        regions
+    }
+
+    #[test]
+    fn no_cells_have_no_regions() {
+        assert!(regions([]).is_empty());
+    }
+
+    #[test]
+    fn connected_cells_form_one_region() {
+        let cells = [(0, 0), (0, 1), (1, 1), (2, 1), (2, 2)];
+        assert_eq!(sorted(regions(cells)), vec![cells.to_vec()]);
+    }
+
+    #[test]
+    fn separated_cells_form_separate_regions() {
+        let cells = [(0, 0), (0, 1), (0, 3), (4, 4)];
+        assert_eq!(
+            sorted(regions(cells)),
+            vec![vec![(0, 0), (0, 1)], vec![(0, 3)], vec![(4, 4)]]
+        );
+    }
+
+    #[test]
+    fn diagonal_cells_are_not_connected() {
+        let cells = [(1, 1), (2, 2), (0, 2)];
+        assert_eq!(regions(cells).len(), 3);
+    }
+
+    #[test]
+    fn regions_wrap_around_holes() {
+        let ring = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 0),
+            (1, 2),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+        ];
+        assert_eq!(sorted(regions(ring)), vec![ring.to_vec()]);
+    }
+
+    // up to five tiles scattered over a 4x4 box, not necessarily connected
+    fn random_choice(rng: &mut StdRng) -> Choice {
+        let mut tiles = (0..rng.random_range(1..=5))
+            .map(|_| (rng.random_range(0..4), rng.random_range(0..4)))
+            .collect::<Vec<(usize, usize)>>();
+        tiles.sort();
+        tiles.dedup();
+        let min_row = tiles.iter().map(|(row, _)| *row).min().expect("tiles");
+        let min_column = tiles
+            .iter()
+            .map(|(_, column)| *column)
+            .min()
+            .expect("tiles");
+        let tiles = tiles
+            .into_iter()
+            .map(|(row, column)| (row - min_row, column - min_column))
+            .collect();
+        Choice::new(Terrain::Forest, Handle::default(), tiles, false)
+    }
+
+    // the tiles of the choice image that end up over the centers of the occupied cells
+    fn tiles_under_sprite(
+        choice: &Choice,
+        pose: &ChoicePose,
+        occupied_tiles: &[(isize, isize)],
+        grid: &Grid,
+    ) -> HashSet<(usize, usize)> {
+        let transform = choice_transform(choice, pose, grid);
+        let half_size = choice.size(grid.cell_size) / 2.0;
+        occupied_tiles
+            .iter()
+            .map(|(row, column)| {
+                let center = grid.top_left_cell_offset
+                    + grid.cell_size * Vec2::new(*column as f32, *row as f32).inverse_y();
+                let mut local = (transform.rotation.inverse()
+                    * (center.extend(0.0) - transform.translation))
+                    .truncate();
+                if pose.orientation.flipped {
+                    local.x *= -1.0;
+                }
+                // the choice image draws the first row at the bottom
+                let tile = (half_size + local) / grid.cell_size - 0.5;
+                assert!(
+                    tile.distance(tile.round()) < 1e-3,
+                    "{pose:?} puts the center of {:?} between tiles",
+                    (row, column)
+                );
+                assert!(
+                    tile.x >= -1e-3 && tile.y >= -1e-3,
+                    "{pose:?} puts {:?} outside the sprite",
+                    (row, column)
+                );
+                (tile.y.round() as usize, tile.x.round() as usize)
+            })
+            .collect()
+    }
+
+    // random sequences of turns, flips and anchors, committed whenever they fit
+    #[test]
+    fn random_placements_match_their_sprites_and_never_overlap() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let grid = Grid {
+            cell_size: Vec2::splat(20.0),
+            dimension: (11, 11),
+            top_left_cell_offset: Vec2::new(-100.0, 100.0),
+            scale: 1.0,
+        };
+        let mut placed_cells = HashSet::new();
+        for _ in 0..2000 {
+            let choice = random_choice(&mut rng);
+            let mut pose = ChoicePose::default();
+            for _ in 0..rng.random_range(0..8) {
+                pose.orientation = match rng.random_range(0..3) {
+                    0 => pose.orientation.turned(rng.random_range(1..4)),
+                    1 => pose.orientation.flipped_horizontally(),
+                    _ => pose.orientation.flipped_vertically(),
+                };
+            }
+            let anchor = (rng.random_range(0..11), rng.random_range(0..11));
+            pose.anchor = Some(anchor);
+
+            let tiles = choice.tiles_at(pose.orientation, anchor);
+            assert_eq!(
+                tiles.iter().collect::<HashSet<_>>().len(),
+                choice.tiles.len(),
+                "{pose:?} folds tiles of {:?} onto each other",
+                choice.tiles
+            );
+            assert_eq!(
+                tiles_under_sprite(&choice, &pose, &tiles, &grid),
+                choice.tiles.iter().copied().collect::<HashSet<_>>(),
+                "{pose:?} of {:?} doesn't match its sprite",
+                choice.tiles
+            );
+
+            if fits_on_grid(&tiles, &placed_cells, &grid) {
+                for tile in tiles {
+                    assert!(placed_cells.insert(tile), "{tile:?} was placed twice");
+                }
+            }
+            // start over before the grid is too full for anything to fit
+            if placed_cells.len() > 80 {
+                placed_cells.clear();
+            }
+        }
+    }
 }
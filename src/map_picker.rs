@@ -0,0 +1,103 @@
+use crate::AppState;
+use crate::asset_manager::{PlayerMaps, SubstitutedAssets};
+use crate::map::MapSide;
+use bevy::prelude::*;
+use strum::IntoEnumIterator;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(AppState::PickMap), spawn_map_picker)
+        .add_systems(Update, pick_map.run_if(in_state(AppState::PickMap)));
+}
+
+#[derive(Component)]
+struct MapOption(MapSide);
+
+fn spawn_map_picker(
+    mut commands: Commands,
+    player_maps: Res<PlayerMaps>,
+    substituted_assets: Res<SubstitutedAssets>,
+) {
+    commands
+        .spawn((
+            StateScoped(AppState::PickMap),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb_u8(40, 32, 24)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Choose a side of the map"),
+                TextFont::from_font_size(32.0),
+            ));
+            parent
+                .spawn(Node {
+                    height: Val::Percent(75.0),
+                    column_gap: Val::Px(32.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for side in MapSide::iter() {
+                        let (image, _) = side.image(&player_maps);
+                        let layout = side.layout();
+                        let mut specials = vec![
+                            format!("{} mountains", layout.mountains.len()),
+                            format!("{} ruins", layout.ruins.len()),
+                        ];
+                        if !layout.wastelands.is_empty() {
+                            specials.push(format!("{} wastelands", layout.wastelands.len()));
+                        }
+                        parent.spawn((
+                            MapOption(side),
+                            Button,
+                            Node {
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                row_gap: Val::Px(8.0),
+                                ..default()
+                            },
+                            children![
+                                (
+                                    Node {
+                                        height: Val::Percent(90.0),
+                                        aspect_ratio: Some(0.7),
+                                        ..default()
+                                    },
+                                    ImageNode::new(image),
+                                ),
+                                Text::new(format!("Side {side:?}: {}", specials.join(", "))),
+                            ],
+                        ));
+                    }
+                });
+            if !substituted_assets.is_empty() {
+                parent.spawn((
+                    Text::new(format!(
+                        "{} assets of the manifest could not be loaded, the defaults are shown instead",
+                        substituted_assets.len()
+                    )),
+                    TextColor(Color::srgb_u8(240, 120, 60)),
+                ));
+            }
+        });
+}
+
+fn pick_map(
+    options: Query<(&Interaction, &MapOption), Changed<Interaction>>,
+    mut map_side: ResMut<MapSide>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (_, option) in options
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        *map_side = option.0;
+        next_state.set(AppState::InGame);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::AppState;
+use crate::map::{Cell, Grid, SelectedChoicePlaced, orthogonal_neighbours, regions};
+use crate::placement::SelectedChoice;
+use crate::scoring::Board;
+use crate::terrain::Terrain;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(PenaltyOverlay::default())
+        .add_observer(inspect_monster_cluster)
+        .add_systems(
+            Update,
+            (
+                clear_monster_cluster_inspection.run_if(on_event::<SelectedChoicePlaced>),
+                toggle_penalty_overlay.run_if(input_just_pressed(KeyCode::KeyM)),
+                show_penalty_overlay.run_if(
+                    resource_changed::<PenaltyOverlay>.or(any_match_filter::<Changed<Cell>>),
+                ),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+#[derive(Debug, Default, Resource)]
+pub struct PenaltyOverlay {
+    pub enabled: bool,
+}
+
+#[derive(Component)]
+struct PenaltyMarker;
+
+#[derive(Component)]
+struct MonsterClusterInfo;
+
+#[derive(Component)]
+struct InspectionHighlight;
+
+fn inspect_monster_cluster(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    cells: Query<(Entity, &Cell)>,
+    highlights: Query<Entity, With<InspectionHighlight>>,
+    info: Query<Entity, With<MonsterClusterInfo>>,
+    selected_choice: Query<(), With<SelectedChoice>>,
+    grid: Res<Grid>,
+) {
+    if trigger.button != PointerButton::Primary || !selected_choice.is_empty() {
+        return;
+    }
+    let Ok((_, clicked_cell)) = cells.get(trigger.target()) else {
+        return;
+    };
+    let clicked_cell = clicked_cell.clone();
+
+    clear_inspection(&mut commands, &highlights, &info);
+    if clicked_cell.terrain != Terrain::Monster {
+        return;
+    }
+
+    let empty_cells = cells
+        .iter()
+        .filter(|(_, cell)| cell.is_empty())
+        .map(|(_, cell)| cell.index)
+        .collect::<HashSet<_>>();
+    let cluster = regions(
+        cells
+            .iter()
+            .filter(|(_, cell)| cell.terrain == Terrain::Monster)
+            .map(|(_, cell)| cell.index),
+    )
+    .into_iter()
+    .find(|region| region.contains(&clicked_cell.index))
+    .expect("the clicked monster");
+    let responsible_cells = cluster
+        .iter()
+        .flat_map(|index| orthogonal_neighbours(*index))
+        .filter(|index| empty_cells.contains(index))
+        .collect::<HashSet<_>>();
+
+    for (entity, cell) in cells.iter() {
+        let color = if cluster.contains(&cell.index) {
+            Color::srgba(1.0, 0.3, 0.3, 0.5)
+        } else if responsible_cells.contains(&cell.index) {
+            Color::srgba(1.0, 0.8, 0.3, 0.5)
+        } else {
+            continue;
+        };
+        commands.entity(entity).with_child((
+            InspectionHighlight,
+            Sprite {
+                color,
+                custom_size: Some(grid.cell_size),
+                ..default()
+            },
+            Pickable::IGNORE,
+            Transform::from_xyz(0.0, 0.0, 0.9),
+        ));
+    }
+    commands.spawn((
+        MonsterClusterInfo,
+        StateScoped(AppState::InGame),
+        Text::new(format!(
+            "Monster cluster of {} cells: -{} points (one per highlighted empty cell)",
+            cluster.len(),
+            responsible_cells.len(),
+        )),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+}
+
+fn clear_monster_cluster_inspection(
+    mut commands: Commands,
+    highlights: Query<Entity, With<InspectionHighlight>>,
+    info: Query<Entity, With<MonsterClusterInfo>>,
+) {
+    clear_inspection(&mut commands, &highlights, &info);
+}
+
+fn clear_inspection(
+    commands: &mut Commands,
+    highlights: &Query<Entity, With<InspectionHighlight>>,
+    info: &Query<Entity, With<MonsterClusterInfo>>,
+) {
+    for entity in highlights.iter().chain(info.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn toggle_penalty_overlay(mut overlay: ResMut<PenaltyOverlay>) {
+    overlay.enabled = !overlay.enabled;
+    info!("monster penalty overlay enabled: {}", overlay.enabled);
+}
+
+fn show_penalty_overlay(
+    mut commands: Commands,
+    overlay: Res<PenaltyOverlay>,
+    cells: Query<(Entity, &Cell)>,
+    markers: Query<Entity, With<PenaltyMarker>>,
+    grid: Res<Grid>,
+) {
+    for marker in markers.iter() {
+        commands.entity(marker).despawn();
+    }
+    if !overlay.enabled {
+        return;
+    }
+    let penalty_cells =
+        Board::new(cells.iter().map(|(_, cell)| cell), grid.dimension).monster_penalty_cells();
+    for (entity, _) in cells
+        .iter()
+        .filter(|(_, cell)| penalty_cells.contains(&cell.index))
+    {
+        commands.entity(entity).with_child((
+            PenaltyMarker,
+            Text2d::new("-1"),
+            TextFont::from_font_size(grid.cell_size.y * 0.5),
+            TextColor(Color::srgb_u8(200, 30, 30)),
+            Pickable::IGNORE,
+            Transform::from_xyz(0.0, 0.0, 0.9),
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::AppState;
+use crate::map::{Cell, Grid, SelectedChoicePlaced, orthogonal_neighbours};
+use crate::terrain::Terrain;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+pub fn plugin(app: &mut App) {
+    app.add_event::<MountainSurrounded>().add_systems(
+        Update,
+        (
+            spawn_mountain_coins,
+            detect_surrounded_mountains.run_if(on_event::<SelectedChoicePlaced>),
+        )
+            .chain()
+            .run_if(in_state(AppState::InGame)),
+    );
+}
+
+#[derive(Component)]
+pub struct MountainCoin {
+    pub collected: bool,
+}
+
+#[derive(Event)]
+pub struct MountainSurrounded(pub Entity);
+
+fn spawn_mountain_coins(
+    mut commands: Commands,
+    cells: Query<(Entity, &Cell), Added<Cell>>,
+    grid: Res<Grid>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (entity, _) in cells
+        .iter()
+        .filter(|(_, cell)| cell.terrain == Terrain::Mountain)
+    {
+        commands.entity(entity).with_child((
+            MountainCoin { collected: false },
+            Mesh2d(meshes.add(Circle::new(grid.cell_size.x * 0.2))),
+            MeshMaterial2d(materials.add(Color::srgb_u8(240, 190, 50))),
+            Pickable::IGNORE,
+            Transform::from_translation((grid.cell_size * 0.25).extend(1.0)),
+        ));
+    }
+}
+
+// only a placed shape earns the coin, painted or pasted boards in free placement never do
+fn detect_surrounded_mountains(
+    mut commands: Commands,
+    mut placements: EventReader<SelectedChoicePlaced>,
+    cells: Query<&Cell>,
+    mut coins: Query<(&mut MountainCoin, &ChildOf, &MeshMaterial2d<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let empty_cells = cells
+        .iter()
+        .filter(|cell| cell.is_empty())
+        .map(|cell| cell.index)
+        .collect::<HashSet<_>>();
+    let placed_tiles = placements
+        .read()
+        .flat_map(|placement| placement.tiles.iter().copied())
+        .collect::<HashSet<_>>();
+    for (mut coin, child_of, material) in coins.iter_mut().filter(|(coin, ..)| !coin.collected) {
+        let mountain = cells.get(child_of.parent()).expect("mountain cell");
+        let neighbours = orthogonal_neighbours(mountain.index);
+        let surrounded = neighbours
+            .iter()
+            .all(|neighbour| !empty_cells.contains(neighbour));
+        let completed_by_placement = neighbours
+            .iter()
+            .any(|neighbour| placed_tiles.contains(neighbour));
+        if !surrounded || !completed_by_placement {
+            continue;
+        }
+        coin.collected = true;
+        if let Some(material) = materials.get_mut(material) {
+            material.color = Color::srgba_u8(120, 120, 120, 120);
+        }
+        commands.send_event(MountainSurrounded(child_of.parent()));
+    }
+}
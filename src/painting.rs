@@ -0,0 +1,149 @@
+use crate::asset_manager::TerrainImages;
+use crate::map::{Cell, CellFeature, Grid};
+use crate::rules::FreePlacement;
+use crate::terrain::Terrain;
+use crate::{AppState, reset_resource};
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(PaintCursor::default())
+        .add_systems(OnEnter(AppState::InGame), reset_resource::<PaintCursor>)
+        .add_systems(
+            Update,
+            (
+                toggle_painting.run_if(input_just_pressed(KeyCode::F9)),
+                (move_paint_cursor, paint_cell, show_paint_cursor)
+                    .chain()
+                    .run_if(|cursor: Res<PaintCursor>| cursor.enabled),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+// transcribes a physical board: WASD moves the cursor, the number keys paint
+// the cell under it and move on to the next one
+#[derive(Debug, Default, Resource)]
+pub struct PaintCursor {
+    pub enabled: bool,
+    pub index: (usize, usize),
+}
+
+#[derive(Component)]
+struct PaintCursorMarker;
+
+const PAINT_KEYS: [(KeyCode, Terrain); 6] = [
+    (KeyCode::Digit0, Terrain::None),
+    (KeyCode::Digit1, Terrain::Forest),
+    (KeyCode::Digit2, Terrain::Village),
+    (KeyCode::Digit3, Terrain::Farm),
+    (KeyCode::Digit4, Terrain::Water),
+    (KeyCode::Digit5, Terrain::Monster),
+];
+
+fn toggle_painting(
+    mut commands: Commands,
+    mut cursor: ResMut<PaintCursor>,
+    free_placement: Res<FreePlacement>,
+    markers: Query<Entity, With<PaintCursorMarker>>,
+) {
+    if !cursor.enabled && !free_placement.enabled {
+        info!("painting is only available in free placement");
+        return;
+    }
+    cursor.enabled = !cursor.enabled;
+    info!(
+        "painting enabled: {} (WASD to move, 0 empty, 1 forest, 2 village, 3 farm, 4 water, 5 monster)",
+        cursor.enabled
+    );
+    for marker in markers.iter() {
+        commands.entity(marker).despawn();
+    }
+}
+
+fn move_paint_cursor(
+    mut cursor: ResMut<PaintCursor>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    grid: Res<Grid>,
+) {
+    let (rows, columns) = grid.dimension;
+    let (mut row, mut column) = cursor.index;
+    if keyboard.just_pressed(KeyCode::KeyW) {
+        row = row.saturating_sub(1);
+    }
+    if keyboard.just_pressed(KeyCode::KeyS) {
+        row = (row + 1).min(rows - 1);
+    }
+    if keyboard.just_pressed(KeyCode::KeyA) {
+        column = column.saturating_sub(1);
+    }
+    if keyboard.just_pressed(KeyCode::KeyD) {
+        column = (column + 1).min(columns - 1);
+    }
+    if cursor.index != (row, column) {
+        cursor.index = (row, column);
+    }
+}
+
+fn paint_cell(
+    mut cursor: ResMut<PaintCursor>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut cells: Query<(&mut Cell, &mut Sprite)>,
+    grid: Res<Grid>,
+    terrain_images: Res<TerrainImages>,
+) {
+    let Some((_, terrain)) = PAINT_KEYS
+        .iter()
+        .find(|(key, _)| keyboard.just_pressed(*key))
+    else {
+        return;
+    };
+    if let Some((mut cell, mut sprite)) = cells
+        .iter_mut()
+        .find(|(cell, _)| cell.index == cursor.index)
+        .filter(|(cell, _)| {
+            cell.terrain != Terrain::Mountain && cell.feature != CellFeature::Wasteland
+        })
+    {
+        cell.terrain = terrain.clone();
+        sprite.image = terrain_images[terrain].clone();
+    }
+
+    // continue row by row like reading the paper board
+    let (rows, columns) = grid.dimension;
+    let (row, column) = cursor.index;
+    cursor.index = if column + 1 < columns {
+        (row, column + 1)
+    } else {
+        ((row + 1) % rows, 0)
+    };
+}
+
+fn show_paint_cursor(
+    mut commands: Commands,
+    cursor: Res<PaintCursor>,
+    cells: Query<(Entity, &Cell)>,
+    markers: Query<Entity, With<PaintCursorMarker>>,
+    grid: Res<Grid>,
+) {
+    if !cursor.is_changed() && !markers.is_empty() {
+        return;
+    }
+    for marker in markers.iter() {
+        commands.entity(marker).despawn();
+    }
+    let Some((entity, _)) = cells.iter().find(|(_, cell)| cell.index == cursor.index) else {
+        return;
+    };
+    commands.entity(entity).with_child((
+        PaintCursorMarker,
+        Sprite {
+            color: Color::srgba(1.0, 0.9, 0.2, 0.4),
+            custom_size: Some(grid.cell_size),
+            ..default()
+        },
+        Pickable::IGNORE,
+        Transform::from_xyz(0.0, 0.0, 0.9),
+    ));
+}
@@ -0,0 +1,137 @@
+use crate::AppState;
+use crate::asset_manager::{Choices, TerrainImages};
+use crate::cards::generate_choice_image;
+use crate::terrain::Terrain;
+use bevy::image::TextureFormatPixelInfo;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+
+const PALETTE_FILE: &str = "palette.txt";
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(load_palette()).add_systems(
+        Update,
+        (
+            cycle_palettes.run_if(input_just_pressed(KeyCode::F3)),
+            // also runs in the first game, to apply the palette saved in the last run
+            apply_palette.run_if(resource_changed::<TerrainPalette>),
+        )
+            .chain()
+            .run_if(in_state(AppState::InGame)),
+    );
+}
+
+#[derive(Clone, Debug, Resource)]
+pub struct TerrainPalette {
+    pub name: &'static str,
+    pub tints: HashMap<Terrain, Color>,
+}
+
+impl TerrainPalette {
+    pub fn presets() -> Vec<Self> {
+        use Terrain::*;
+        vec![
+            Self {
+                name: "classic",
+                tints: HashMap::new(),
+            },
+            Self {
+                name: "vivid",
+                tints: HashMap::from([
+                    (Forest, Color::srgb(0.7, 1.0, 0.7)),
+                    (Village, Color::srgb(1.0, 0.75, 0.75)),
+                    (Farm, Color::srgb(1.0, 1.0, 0.6)),
+                    (Water, Color::srgb(0.6, 0.8, 1.0)),
+                    (Monster, Color::srgb(0.85, 0.6, 1.0)),
+                ]),
+            },
+            Self {
+                name: "muted",
+                tints: HashMap::from([
+                    (Forest, Color::srgb(0.8, 0.85, 0.8)),
+                    (Village, Color::srgb(0.85, 0.8, 0.8)),
+                    (Farm, Color::srgb(0.85, 0.85, 0.75)),
+                    (Water, Color::srgb(0.75, 0.8, 0.85)),
+                    (Monster, Color::srgb(0.8, 0.75, 0.85)),
+                ]),
+            },
+        ]
+    }
+
+    pub fn tint(&self, terrain: &Terrain) -> Color {
+        self.tints.get(terrain).copied().unwrap_or(Color::WHITE)
+    }
+}
+
+// the palette chosen in the last run, the classic one if there is none
+fn load_palette() -> TerrainPalette {
+    let name = fs::read_to_string(PALETTE_FILE).unwrap_or_default();
+    let mut presets = TerrainPalette::presets();
+    let index = presets
+        .iter()
+        .position(|preset| preset.name == name.trim())
+        .unwrap_or(0);
+    presets.swap_remove(index)
+}
+
+fn cycle_palettes(mut palette: ResMut<TerrainPalette>) {
+    let presets = TerrainPalette::presets();
+    let index = presets
+        .iter()
+        .position(|preset| preset.name == palette.name)
+        .map_or(0, |index| (index + 1) % presets.len());
+    *palette = presets[index].clone();
+    info!("terrain palette: {}", palette.name);
+    if let Err(error) = fs::write(PALETTE_FILE, palette.name) {
+        warn!("could not save the terrain palette: {error}");
+    }
+}
+
+// terrain images are tinted in place, so every sprite and generated choice image
+// referencing them picks up the palette without touching their components
+fn apply_palette(
+    palette: Res<TerrainPalette>,
+    terrain_images: Res<TerrainImages>,
+    choices: Res<Choices>,
+    mut images: ResMut<Assets<Image>>,
+    mut originals: Local<HashMap<Terrain, Image>>,
+) {
+    if originals.is_empty() {
+        for (terrain, handle) in terrain_images.iter() {
+            let image = images.get(handle).expect("terrain image");
+            originals.insert(terrain.clone(), image.clone());
+        }
+    }
+    let tinted = originals
+        .iter()
+        .map(|(terrain, image)| (terrain, tint_image(image, palette.tint(terrain))))
+        .collect::<HashMap<_, _>>();
+
+    for choice in choices.values().flatten() {
+        images.insert(
+            &choice.image,
+            generate_choice_image(&choice.tiles, &tinted[&choice.terrain]),
+        );
+    }
+    for (terrain, image) in tinted {
+        images.insert(&terrain_images[terrain], image);
+    }
+}
+
+fn tint_image(image: &Image, tint: Color) -> Image {
+    let mut tinted = image.clone();
+    let tint = tint.to_srgba();
+    if image.texture_descriptor.format.pixel_size() != 4 {
+        return tinted;
+    }
+    if let Some(data) = tinted.data.as_mut() {
+        for pixel in data.chunks_exact_mut(4) {
+            pixel[0] = (pixel[0] as f32 * tint.red) as u8;
+            pixel[1] = (pixel[1] as f32 * tint.green) as u8;
+            pixel[2] = (pixel[2] as f32 * tint.blue) as u8;
+        }
+    }
+    tinted
+}
@@ -0,0 +1,174 @@
+use crate::map::SelectedChoicePlaced;
+use crate::terrain::Choice;
+use crate::{DrawnCard, TurnPhase};
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+use strum::{EnumIter, IntoEnumIterator};
+
+pub fn plugin(app: &mut App) {
+    app.add_event::<SelectedChoiceSpawned>()
+        .add_event::<SelectedChoiceDespawned>()
+        .add_observer(keep_single_selected_choice)
+        .add_observer(announce_despawned_choice)
+        .add_systems(OnExit(TurnPhase::PlaceShape), despawn_selected_choice)
+        .add_systems(
+            Update,
+            (
+                despawn_selected_choice.run_if(on_event::<SelectedChoicePlaced>),
+                cancel_selected_choice
+                    .run_if(
+                        input_just_pressed(KeyCode::Escape)
+                            .or(input_just_pressed(MouseButton::Right)),
+                    )
+                    .run_if(in_state(TurnPhase::PlaceShape)),
+            ),
+        );
+}
+
+// the shape following the cursor until it is placed, owned by this plugin:
+// spawning a new one replaces the old one, and it is gone once the turn
+// leaves the place phase
+#[derive(Component)]
+pub struct SelectedChoice {
+    pub choice: Choice,
+    pub pose: ChoicePose,
+    pub valid_to_place: bool,
+    pub occupied_tiles: Option<Vec<(isize, isize)>>,
+    pub latest_hovered_cell: Option<Entity>,
+}
+
+// where and how a choice lies on the grid in whole cells, the sprite is only
+// derived from it, so rules can reason about placements without a transform
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ChoicePose {
+    pub anchor: Option<(usize, usize)>,
+    pub orientation: Orientation,
+}
+
+// one of the eight ways a choice can lie, mirrored horizontally before it is turned,
+// a vertical mirroring is the same as a horizontal one turned by half
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Orientation {
+    pub rotation: Rotation,
+    pub flipped: bool,
+}
+
+// counter-clockwise quarter turns
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, Hash, PartialEq)]
+pub enum Rotation {
+    #[default]
+    R0,
+    R90,
+    R180,
+    R270,
+}
+
+#[derive(Event)]
+pub struct SelectedChoiceSpawned(pub Entity);
+
+#[derive(Event)]
+pub struct SelectedChoiceDespawned(pub Entity);
+
+impl SelectedChoice {
+    pub fn new(choice: Choice) -> Self {
+        Self {
+            choice,
+            pose: ChoicePose::default(),
+            valid_to_place: false,
+            occupied_tiles: None,
+            latest_hovered_cell: None,
+        }
+    }
+}
+
+impl Orientation {
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Rotation::iter()
+            .flat_map(|rotation| [false, true].map(|flipped| Self { rotation, flipped }))
+    }
+
+    pub fn turned(self, quarter_turns: u8) -> Self {
+        Self {
+            rotation: self.rotation.turned(quarter_turns),
+            ..self
+        }
+    }
+
+    pub fn flipped_horizontally(self) -> Self {
+        Self {
+            flipped: !self.flipped,
+            ..self
+        }
+    }
+
+    pub fn flipped_vertically(self) -> Self {
+        Self {
+            rotation: self.rotation.turned(2),
+            flipped: !self.flipped,
+        }
+    }
+
+    pub fn to_quat(self) -> Quat {
+        self.rotation.to_quat()
+    }
+}
+
+impl Rotation {
+    pub fn quarter_turns(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_quarter_turns(quarter_turns: u8) -> Self {
+        match quarter_turns % 4 {
+            0 => Rotation::R0,
+            1 => Rotation::R90,
+            2 => Rotation::R180,
+            _ => Rotation::R270,
+        }
+    }
+
+    pub fn turned(self, quarter_turns: u8) -> Self {
+        Self::from_quarter_turns(self.quarter_turns() + quarter_turns)
+    }
+
+    pub fn to_quat(self) -> Quat {
+        Quat::from_rotation_z(self.quarter_turns() as f32 * FRAC_PI_2)
+    }
+}
+
+fn keep_single_selected_choice(
+    trigger: Trigger<OnAdd, SelectedChoice>,
+    mut commands: Commands,
+    selected_choices: Query<Entity, With<SelectedChoice>>,
+) {
+    for entity in selected_choices
+        .iter()
+        .filter(|entity| *entity != trigger.target())
+    {
+        commands.entity(entity).despawn();
+    }
+    commands.send_event(SelectedChoiceSpawned(trigger.target()));
+}
+
+fn announce_despawned_choice(trigger: Trigger<OnRemove, SelectedChoice>, mut commands: Commands) {
+    commands.send_event(SelectedChoiceDespawned(trigger.target()));
+}
+
+fn despawn_selected_choice(
+    mut commands: Commands,
+    selected_choices: Query<Entity, With<SelectedChoice>>,
+) {
+    for entity in selected_choices.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+// back to the choices of the same card, leaving the place phase despawns the selected choice
+fn cancel_selected_choice(
+    mut drawn_card: Single<&mut DrawnCard>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
+) {
+    next_phase.set(TurnPhase::ChooseShape);
+    drawn_card.set_changed();
+}
@@ -0,0 +1,159 @@
+use crate::rules::{FreePlacement, Hardcore};
+use crate::scoring::SeasonScores;
+use crate::{AppState, GameSetup};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(AppState::GameOver), spawn_results)
+        .add_systems(Update, start_new_game.run_if(in_state(AppState::GameOver)));
+}
+
+#[derive(Clone, Component, Copy)]
+enum NewGameButton {
+    // same map side, deck order and edicts as the game that just ended
+    Rematch,
+    NewSetup,
+}
+
+const COLUMNS: [&str; 6] = ["Season", "Edict", "Edict", "Coins", "Monsters", "Total"];
+
+// laid out like the paper score sheet: both edicts, coins and monsters of every
+// season next to each other and the sum of all seasons below
+fn spawn_results(
+    mut commands: Commands,
+    season_scores: Res<SeasonScores>,
+    game_setup: Res<GameSetup>,
+    free_placement: Res<FreePlacement>,
+    hardcore: Res<Hardcore>,
+) {
+    let mut rows = vec![COLUMNS.map(String::from).to_vec()];
+    for score in season_scores.0.iter() {
+        let [first, second] = score.season.edict_slots();
+        let edict = |index: usize, letter: char| {
+            let points = score.edicts.get(index).map_or(0, |(_, points)| *points);
+            format!("{letter}: {points}")
+        };
+        rows.push(vec![
+            score.season.name().to_string(),
+            edict(0, first.letter()),
+            edict(1, second.letter()),
+            score.coins.to_string(),
+            format!("-{}", score.monsters),
+            score.total().to_string(),
+        ]);
+    }
+    let total = season_scores
+        .0
+        .iter()
+        .map(|score| score.total())
+        .sum::<i32>();
+
+    commands
+        .spawn((
+            StateScoped(AppState::GameOver),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb_u8(40, 32, 24)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((Text::new("Final score"), TextFont::from_font_size(40.0)));
+            for (index, row) in rows.into_iter().enumerate() {
+                let color = if index == 0 {
+                    Color::srgb_u8(200, 170, 110)
+                } else {
+                    Color::WHITE
+                };
+                parent
+                    .spawn(Node {
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for text in row {
+                            parent.spawn((
+                                Node {
+                                    width: Val::Px(110.0),
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                children![(Text::new(text), TextColor(color))],
+                            ));
+                        }
+                    });
+            }
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(16.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(format!("Total: {total}")),
+                        TextFont::from_font_size(32.0),
+                    ));
+                    if hardcore.enabled {
+                        parent.spawn((
+                            Text::new("Hardcore"),
+                            TextColor(Color::srgb_u8(220, 40, 40)),
+                        ));
+                    }
+                });
+            if free_placement.used_this_game {
+                parent.spawn((
+                    Text::new("Unvalidated (free placement)"),
+                    TextColor(Color::srgb_u8(240, 120, 60)),
+                ));
+            }
+            parent.spawn(Text::new(format!("Seed: {}", game_setup.seed)));
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(16.0),
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (button, label) in [
+                        (NewGameButton::Rematch, "Rematch"),
+                        (NewGameButton::NewSetup, "New random setup"),
+                    ] {
+                        parent.spawn((
+                            button,
+                            Button,
+                            Node {
+                                padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb_u8(90, 70, 50)),
+                            children![Text::new(label)],
+                        ));
+                    }
+                });
+        });
+}
+
+fn start_new_game(
+    buttons: Query<(&Interaction, &NewGameButton), Changed<Interaction>>,
+    mut game_setup: ResMut<GameSetup>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (_, button) in buttons
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        match button {
+            NewGameButton::Rematch => next_state.set(AppState::InGame),
+            NewGameButton::NewSetup => {
+                *game_setup = GameSetup::random();
+                next_state.set(AppState::PickMap);
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::cards::{DrawableCard, Exploration};
+use crate::map::{Cell, CellFeature, Grid};
+use crate::{AppState, DrawnCard, TurnPhase, reset_resource};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(RuinsPending::default())
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (reset_resource::<RuinsPending>, spawn_ruins_banner),
+        )
+        .add_systems(OnEnter(TurnPhase::DrawCard), clear_ruins_after_turn)
+        .add_systems(
+            Update,
+            (
+                spawn_ruins_overlays,
+                flip_after_ruins.run_if(in_state(TurnPhase::ChooseShape)),
+                update_ruins_banner.run_if(resource_changed::<RuinsPending>),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+// set while the shape following a ruins card has to cover a ruins space
+#[derive(Debug, Default, Resource)]
+pub struct RuinsPending(pub bool);
+
+// on the single squares offered when no shape of the card can cover a ruins space,
+// and on the selected choice taken from them
+#[derive(Clone, Component, Copy)]
+pub struct RuinsWaived;
+
+#[derive(Component)]
+struct RuinsBanner;
+
+pub fn is_ruins(card: &DrawableCard) -> bool {
+    matches!(
+        card,
+        DrawableCard::Exploration(Exploration::TempleRuins05 | Exploration::OutpostRuins06)
+    )
+}
+
+fn spawn_ruins_overlays(
+    mut commands: Commands,
+    cells: Query<(Entity, &Cell), Added<Cell>>,
+    grid: Res<Grid>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (entity, _) in cells
+        .iter()
+        .filter(|(_, cell)| cell.feature == CellFeature::Ruins)
+    {
+        let radius = grid.cell_size.min_element() * 0.45;
+        commands.entity(entity).with_child((
+            Mesh2d(meshes.add(Annulus::new(radius * 0.8, radius))),
+            MeshMaterial2d(materials.add(Color::srgba_u8(150, 120, 80, 200))),
+            Pickable::IGNORE,
+            Transform::from_xyz(0.0, 0.0, 0.8),
+        ));
+    }
+}
+
+fn flip_after_ruins(
+    drawn_card: Single<&DrawnCard, Changed<DrawnCard>>,
+    cards: Query<&DrawableCard>,
+    mut ruins_pending: ResMut<RuinsPending>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
+) {
+    match cards.get(drawn_card.0).expect("card") {
+        card if is_ruins(card) => {
+            ruins_pending.0 = true;
+            next_phase.set(TurnPhase::DrawCard);
+        }
+        // monsters ignore the ruins
+        DrawableCard::Ambush(_) => ruins_pending.0 = false,
+        DrawableCard::Exploration(_) => {}
+    }
+}
+
+fn clear_ruins_after_turn(
+    drawn_card: Single<&DrawnCard>,
+    cards: Query<&DrawableCard>,
+    mut ruins_pending: ResMut<RuinsPending>,
+) {
+    if !is_ruins(cards.get(drawn_card.0).expect("card")) {
+        ruins_pending.0 = false;
+    }
+}
+
+fn spawn_ruins_banner(mut commands: Commands) {
+    commands.spawn((
+        RuinsBanner,
+        StateScoped(AppState::InGame),
+        Text::new("Ruins: the next shape must cover a ruins space"),
+        TextColor(Color::srgb_u8(200, 170, 110)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            left: Val::Percent(45.0),
+            ..default()
+        },
+        Visibility::Hidden,
+    ));
+}
+
+fn update_ruins_banner(
+    ruins_pending: Res<RuinsPending>,
+    mut banner: Single<&mut Visibility, With<RuinsBanner>>,
+) {
+    **banner = if ruins_pending.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+}
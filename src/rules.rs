@@ -0,0 +1,141 @@
+use crate::history::PlacementLog;
+use crate::map::ScoringPreview;
+use crate::{AppState, ChoiceUI, DrawnCard};
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(FreePlacement::default())
+        .insert_resource(Hardcore::default())
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (
+                reset_free_placement_use,
+                spawn_free_placement_banner,
+                spawn_hardcore_banner,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                toggle_free_placement.run_if(input_just_pressed(KeyCode::F2)),
+                toggle_hardcore.run_if(input_just_pressed(KeyCode::F4)),
+            )
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+// overlaps are still blocked, but choices are never disabled by the legality check
+#[derive(Debug, Default, Resource)]
+pub struct FreePlacement {
+    pub enabled: bool,
+    pub used_this_game: bool,
+}
+
+// no hints, legality badges or placement previews
+#[derive(Debug, Default, Resource)]
+pub struct Hardcore {
+    pub enabled: bool,
+}
+
+#[derive(Component)]
+struct FreePlacementBanner;
+
+#[derive(Component)]
+struct HardcoreBanner;
+
+// the rules chosen last game carry over, a game started in free placement is still unvalidated
+fn reset_free_placement_use(mut free_placement: ResMut<FreePlacement>) {
+    free_placement.used_this_game = free_placement.enabled;
+}
+
+// the banner is gone with the game, the rule itself carries over to the next one
+fn spawn_free_placement_banner(mut commands: Commands, free_placement: Res<FreePlacement>) {
+    if free_placement.enabled {
+        commands.spawn(free_placement_banner());
+    }
+}
+
+fn free_placement_banner() -> impl Bundle {
+    (
+        FreePlacementBanner,
+        StateScoped(AppState::InGame),
+        Text::new("Free placement: this game is unvalidated"),
+        TextColor(Color::srgb_u8(240, 120, 60)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    )
+}
+
+fn toggle_free_placement(
+    mut commands: Commands,
+    mut free_placement: ResMut<FreePlacement>,
+    hardcore: Res<Hardcore>,
+    banner: Option<Single<Entity, With<FreePlacementBanner>>>,
+    mut drawn_card: Single<&mut DrawnCard>,
+    choice_ui: Option<Single<(), With<ChoiceUI>>>,
+) {
+    if hardcore.enabled {
+        info!("free placement is not available in hardcore games");
+        return;
+    }
+    free_placement.enabled = !free_placement.enabled;
+    if free_placement.enabled {
+        free_placement.used_this_game = true;
+        commands.spawn(free_placement_banner());
+    } else if let Some(banner) = banner {
+        commands.entity(*banner).despawn();
+    }
+    if choice_ui.is_some() {
+        // rebuild the offered choices with the new rules
+        drawn_card.set_changed();
+    }
+}
+
+fn spawn_hardcore_banner(mut commands: Commands, hardcore: Res<Hardcore>) {
+    if hardcore.enabled {
+        commands.spawn(hardcore_banner());
+    }
+}
+
+fn hardcore_banner() -> impl Bundle {
+    (
+        HardcoreBanner,
+        StateScoped(AppState::InGame),
+        Text::new("Hardcore"),
+        TextColor(Color::srgb_u8(220, 40, 40)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    )
+}
+
+fn toggle_hardcore(
+    mut commands: Commands,
+    mut hardcore: ResMut<Hardcore>,
+    free_placement: Res<FreePlacement>,
+    placement_log: Res<PlacementLog>,
+    banner: Option<Single<Entity, With<HardcoreBanner>>>,
+    mut drawn_card: Single<&mut DrawnCard>,
+    mut scoring_preview: ResMut<ScoringPreview>,
+) {
+    if !placement_log.0.is_empty() || free_placement.used_this_game {
+        info!("hardcore can only be toggled before the first placement of a validated game");
+        return;
+    }
+    hardcore.enabled = !hardcore.enabled;
+    if hardcore.enabled {
+        scoring_preview.slot = None;
+        commands.spawn(hardcore_banner());
+    } else if let Some(banner) = banner {
+        commands.entity(*banner).despawn();
+    }
+    drawn_card.set_changed();
+}
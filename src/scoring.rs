@@ -0,0 +1,710 @@
+use crate::cards::{FarmScoring, HouseScoring, Scoring, Season, ShapeScoring, TreeScoring};
+use crate::coins::Coins;
+use crate::hooks::GameSet;
+use crate::map::{Cell, CellFeature, Grid, orthogonal_neighbours, regions};
+use crate::terrain::Terrain;
+use crate::{AppState, Scroll, reset_resource};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::ops::Add;
+use strum::IntoEnumIterator;
+
+pub fn plugin(app: &mut App) {
+    app.add_event::<SeasonEnded>()
+        .insert_resource(SeasonScores::default())
+        .add_observer(open_scroll_edict)
+        .add_systems(OnEnter(AppState::InGame), reset_resource::<SeasonScores>)
+        .add_systems(
+            Update,
+            (
+                score_season
+                    .in_set(GameSet::Scoring)
+                    .run_if(on_event::<SeasonEnded>),
+                close_zoomed_edict,
+            )
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+#[derive(Event)]
+pub struct SeasonEnded(pub Season);
+
+// position of an edict below the scrolls, 0 for A up to 3 for D
+#[derive(Clone, Component, Copy, Debug, Eq, PartialEq)]
+pub struct EdictSlot(pub usize);
+
+#[derive(Component)]
+struct ZoomedEdict;
+
+#[derive(Clone, Debug)]
+pub struct SeasonScore {
+    pub season: Season,
+    pub edicts: Vec<(Scoring, u32)>,
+    pub coins: u32,
+    pub monsters: u32,
+}
+
+impl EdictSlot {
+    pub fn letter(&self) -> char {
+        (b'A' + self.0 as u8) as char
+    }
+
+    pub fn seasons(&self) -> Vec<Season> {
+        Season::iter()
+            .filter(|season| season.edict_slots().contains(self))
+            .collect()
+    }
+}
+
+impl EdictScore {
+    fn new(points: u32, cells: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        Self {
+            points,
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    fn per_cell(points: u32, cells: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let cells = cells.into_iter().collect::<HashSet<_>>();
+        Self::new(points * cells.len() as u32, cells)
+    }
+
+    fn per_cluster<C: IntoIterator<Item = (usize, usize)>>(
+        points: u32,
+        clusters: impl IntoIterator<Item = C>,
+    ) -> Self {
+        clusters
+            .into_iter()
+            .map(|cluster| Self::new(points, cluster))
+            .fold(Self::default(), |total, score| total + score)
+    }
+}
+
+impl Add for EdictScore {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self.points += other.points;
+        self.cells.extend(other.cells);
+        self
+    }
+}
+
+impl SeasonScore {
+    pub fn total(&self) -> i32 {
+        let edicts = self.edicts.iter().map(|(_, points)| points).sum::<u32>();
+        (edicts + self.coins) as i32 - self.monsters as i32
+    }
+}
+
+// the points of an edict and the cells that earned them
+#[derive(Clone, Debug, Default)]
+pub struct EdictScore {
+    pub points: u32,
+    pub cells: HashSet<(usize, usize)>,
+}
+
+#[derive(Debug, Default, Resource)]
+pub struct SeasonScores(pub Vec<SeasonScore>);
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    pub dimension: (usize, usize),
+    terrains: HashMap<(usize, usize), Terrain>,
+    ruins: HashSet<(usize, usize)>,
+    wastelands: HashSet<(usize, usize)>,
+}
+
+impl Board {
+    pub fn new<'a>(cells: impl IntoIterator<Item = &'a Cell>, dimension: (usize, usize)) -> Self {
+        let cells = cells.into_iter().collect::<Vec<_>>();
+        Self {
+            dimension,
+            terrains: cells
+                .iter()
+                .map(|cell| (cell.index, cell.terrain.clone()))
+                .collect(),
+            ruins: cells
+                .iter()
+                .filter(|cell| cell.feature == CellFeature::Ruins)
+                .map(|cell| cell.index)
+                .collect(),
+            wastelands: cells
+                .iter()
+                .filter(|cell| cell.feature == CellFeature::Wasteland)
+                .map(|cell| cell.index)
+                .collect(),
+        }
+    }
+
+    pub fn terrain(&self, index: (usize, usize)) -> Option<&Terrain> {
+        self.terrains.get(&index)
+    }
+
+    pub fn is_ruins(&self, index: (usize, usize)) -> bool {
+        self.ruins.contains(&index)
+    }
+
+    // wastelands count as filled although nothing is drawn on them
+    pub fn is_empty(&self, index: (usize, usize)) -> bool {
+        self.terrain(index) == Some(&Terrain::None) && !self.wastelands.contains(&index)
+    }
+
+    pub fn is_filled(&self, index: (usize, usize)) -> bool {
+        self.terrain(index).is_some() && !self.is_empty(index)
+    }
+
+    pub fn indices(&self, terrain: &Terrain) -> impl Iterator<Item = (usize, usize)> {
+        self.terrains
+            .iter()
+            .filter(move |(_, cell_terrain)| *cell_terrain == terrain)
+            .map(|(index, _)| *index)
+    }
+
+    pub fn is_on_edge(&self, (row, column): (usize, usize)) -> bool {
+        let (rows, columns) = self.dimension;
+        row == 0 || column == 0 || row + 1 == rows || column + 1 == columns
+    }
+
+    pub fn neighbours(&self, index: (usize, usize)) -> Vec<(usize, usize)> {
+        orthogonal_neighbours(index)
+            .into_iter()
+            .filter(|neighbour| self.terrains.contains_key(neighbour))
+            .collect()
+    }
+
+    pub fn is_next_to(&self, index: (usize, usize), terrain: &Terrain) -> bool {
+        self.neighbours(index)
+            .iter()
+            .any(|neighbour| self.terrain(*neighbour) == Some(terrain))
+    }
+
+    // every empty cell next to a monster costs a point
+    pub fn monster_penalty_cells(&self) -> HashSet<(usize, usize)> {
+        self.indices(&Terrain::Monster)
+            .flat_map(|index| self.neighbours(index))
+            .filter(|index| self.is_empty(*index))
+            .collect()
+    }
+
+    pub fn clusters(&self, terrain: &Terrain) -> Vec<HashSet<(usize, usize)>> {
+        regions(self.indices(terrain))
+    }
+}
+
+impl Season {
+    // spring scores A and B, summer B and C, fall C and D, winter D and A
+    pub fn edict_slots(&self) -> [EdictSlot; 2] {
+        let first = match self {
+            Season::Spring18 => 0,
+            Season::Summer19 => 1,
+            Season::Fall20 => 2,
+            Season::Winter21 => 3,
+        };
+        [EdictSlot(first), EdictSlot((first + 1) % 4)]
+    }
+}
+
+pub fn score_edict(edict: &Scoring, board: &Board) -> EdictScore {
+    match edict {
+        Scoring::Tree(edict) => score_forests(edict, board),
+        Scoring::Farm(edict) => score_farms_and_waters(edict, board),
+        Scoring::House(edict) => score_villages(edict, board),
+        Scoring::Shape(edict) => score_spaces(edict, board),
+    }
+}
+
+fn score_forests(edict: &TreeScoring, board: &Board) -> EdictScore {
+    let forests = board.indices(&Terrain::Forest).collect::<Vec<_>>();
+    match edict {
+        TreeScoring::SentinelWood26 => {
+            EdictScore::per_cell(1, cells_where(&forests, |forest| board.is_on_edge(forest)))
+        }
+        // rows and columns count separately
+        TreeScoring::Greenbough27 => {
+            let rows = forests.iter().map(|(row, _)| row).collect::<HashSet<_>>();
+            let columns = forests
+                .iter()
+                .map(|(_, column)| column)
+                .collect::<HashSet<_>>();
+            EdictScore::new((rows.len() + columns.len()) as u32, forests.clone())
+        }
+        // the edge of the map counts as filled
+        TreeScoring::Treetower28 => EdictScore::per_cell(
+            1,
+            cells_where(&forests, |forest| {
+                board
+                    .neighbours(forest)
+                    .iter()
+                    .all(|neighbour| board.is_filled(*neighbour))
+            }),
+        ),
+        // a mountain scores once even if several clusters connect it
+        TreeScoring::StonesideForest29 => {
+            let connections = board
+                .clusters(&Terrain::Forest)
+                .into_iter()
+                .map(|cluster| {
+                    let mountains = cluster
+                        .iter()
+                        .flat_map(|forest| board.neighbours(*forest))
+                        .filter(|neighbour| board.terrain(*neighbour) == Some(&Terrain::Mountain))
+                        .collect::<HashSet<_>>();
+                    (cluster, mountains)
+                })
+                .filter(|(_, mountains)| mountains.len() >= 2)
+                .collect::<Vec<_>>();
+            let mountains = connections
+                .iter()
+                .flat_map(|(_, mountains)| mountains.iter().copied())
+                .collect::<HashSet<_>>();
+            let forests = connections.into_iter().flat_map(|(cluster, _)| cluster);
+            EdictScore::new(
+                3 * mountains.len() as u32,
+                mountains.into_iter().chain(forests),
+            )
+        }
+    }
+}
+
+fn score_farms_and_waters(edict: &FarmScoring, board: &Board) -> EdictScore {
+    let farms = board.indices(&Terrain::Farm).collect::<Vec<_>>();
+    let waters = board.indices(&Terrain::Water).collect::<Vec<_>>();
+    match edict {
+        FarmScoring::CanalLake30 => {
+            EdictScore::per_cell(
+                1,
+                cells_where(&waters, |water| board.is_next_to(water, &Terrain::Farm)),
+            ) + EdictScore::per_cell(
+                1,
+                cells_where(&farms, |farm| board.is_next_to(farm, &Terrain::Water)),
+            )
+        }
+        FarmScoring::MagesValley31 => {
+            EdictScore::per_cell(
+                2,
+                cells_where(&waters, |water| board.is_next_to(water, &Terrain::Mountain)),
+            ) + EdictScore::per_cell(
+                1,
+                cells_where(&farms, |farm| board.is_next_to(farm, &Terrain::Mountain)),
+            )
+        }
+        FarmScoring::TheGoldenGranary32 => {
+            let waters_next_to_ruins = cells_where(&waters, |water| {
+                board
+                    .neighbours(water)
+                    .iter()
+                    .any(|neighbour| board.is_ruins(*neighbour))
+            });
+            EdictScore::per_cell(1, waters_next_to_ruins)
+                + EdictScore::per_cell(3, cells_where(&farms, |farm| board.is_ruins(farm)))
+        }
+        // clusters touching the other terrain or the edge of the map don't count
+        FarmScoring::ShoresideExpanse33 => {
+            let lonely_clusters = [
+                (Terrain::Farm, Terrain::Water),
+                (Terrain::Water, Terrain::Farm),
+            ]
+            .iter()
+            .flat_map(|(terrain, other)| {
+                board.clusters(terrain).into_iter().filter(|cluster| {
+                    cluster
+                        .iter()
+                        .all(|index| !board.is_on_edge(*index) && !board.is_next_to(*index, other))
+                })
+            })
+            .collect::<Vec<_>>();
+            EdictScore::per_cluster(3, lonely_clusters)
+        }
+    }
+}
+
+fn score_villages(edict: &HouseScoring, board: &Board) -> EdictScore {
+    let mut clusters = board.clusters(&Terrain::Village);
+    clusters.sort_by_key(|cluster| std::cmp::Reverse(cluster.len()));
+    match edict {
+        HouseScoring::Wildholds34 => {
+            clusters.retain(|cluster| cluster.len() >= 6);
+            EdictScore::per_cluster(8, clusters)
+        }
+        HouseScoring::GreatCity35 => clusters
+            .into_iter()
+            .find(|cluster| {
+                cluster
+                    .iter()
+                    .all(|village| !board.is_next_to(*village, &Terrain::Mountain))
+            })
+            .map_or_else(EdictScore::default, |cluster| {
+                EdictScore::per_cell(1, cluster)
+            }),
+        // empty cells and wastelands have no terrain type
+        HouseScoring::GreengoldPlains36 => {
+            clusters.retain(|cluster| {
+                let neighbouring_terrains = cluster
+                    .iter()
+                    .flat_map(|village| board.neighbours(*village))
+                    .filter_map(|neighbour| board.terrain(neighbour))
+                    .filter(|terrain| !matches!(terrain, Terrain::None | Terrain::Village))
+                    .collect::<HashSet<_>>();
+                neighbouring_terrains.len() >= 3
+            });
+            EdictScore::per_cluster(3, clusters)
+        }
+        // of two equally large clusters one is the largest and the other the second largest
+        HouseScoring::Shieldgate37 => clusters
+            .into_iter()
+            .nth(1)
+            .map_or_else(EdictScore::default, |cluster| {
+                EdictScore::per_cell(2, cluster)
+            }),
+    }
+}
+
+fn score_spaces(edict: &ShapeScoring, board: &Board) -> EdictScore {
+    let (rows, columns) = board.dimension;
+    match edict {
+        ShapeScoring::Borderlands38 => {
+            let full_rows = (0..rows)
+                .map(|row| (0..columns).map(|column| (row, column)).collect::<Vec<_>>())
+                .filter(|line| line.iter().all(|index| board.is_filled(*index)));
+            let full_columns = (0..columns)
+                .map(|column| (0..rows).map(|row| (row, column)).collect::<Vec<_>>())
+                .filter(|line| line.iter().all(|index| board.is_filled(*index)));
+            EdictScore::per_cluster(6, full_rows.chain(full_columns))
+        }
+        ShapeScoring::LostBarony39 => {
+            let (side, square) = largest_filled_square(board);
+            EdictScore::new(3 * side as u32, square)
+        }
+        // every diagonal running down to the right from the left edge ends at the bottom edge
+        ShapeScoring::TheBrokenRoad40 => {
+            let full_diagonals = (0..rows)
+                .map(|start| (start..rows).zip(0..columns).collect::<Vec<_>>())
+                .filter(|diagonal| diagonal.iter().all(|index| board.is_filled(*index)));
+            EdictScore::per_cluster(3, full_diagonals)
+        }
+        // the edge of the map counts as filled
+        ShapeScoring::TheCauldrons41 => {
+            let empty_cells = board.indices(&Terrain::None).collect::<Vec<_>>();
+            EdictScore::per_cell(
+                1,
+                cells_where(&empty_cells, |index| {
+                    board.is_empty(index)
+                        && board
+                            .neighbours(index)
+                            .iter()
+                            .all(|neighbour| board.is_filled(*neighbour))
+                }),
+            )
+        }
+    }
+}
+
+// the side length and the cells of the largest square of filled cells
+fn largest_filled_square(board: &Board) -> (usize, Vec<(usize, usize)>) {
+    let (rows, columns) = board.dimension;
+    // side of the largest square with its bottom right corner in each cell
+    let mut sides = vec![vec![0; columns]; rows];
+    let mut largest = None;
+    for row in 0..rows {
+        for column in 0..columns {
+            if !board.is_filled((row, column)) {
+                continue;
+            }
+            let side = if row == 0 || column == 0 {
+                1
+            } else {
+                1 + sides[row - 1][column]
+                    .min(sides[row][column - 1])
+                    .min(sides[row - 1][column - 1])
+            };
+            sides[row][column] = side;
+            if largest.is_none_or(|(largest_side, _)| side > largest_side) {
+                largest = Some((side, (row, column)));
+            }
+        }
+    }
+    let Some((side, (row, column))) = largest else {
+        return (0, Vec::new());
+    };
+    let square = (row + 1 - side..=row)
+        .flat_map(|row| (column + 1 - side..=column).map(move |column| (row, column)))
+        .collect();
+    (side, square)
+}
+
+fn cells_where(
+    indices: &[(usize, usize)],
+    predicate: impl Fn((usize, usize)) -> bool,
+) -> Vec<(usize, usize)> {
+    indices
+        .iter()
+        .copied()
+        .filter(|index| predicate(*index))
+        .collect()
+}
+
+fn score_season(
+    mut event_reader: EventReader<SeasonEnded>,
+    mut season_scores: ResMut<SeasonScores>,
+    edicts: Query<(&Scoring, &EdictSlot)>,
+    cells: Query<&Cell>,
+    grid: Res<Grid>,
+    coins: Res<Coins>,
+) {
+    let board = Board::new(cells.iter(), grid.dimension);
+    for SeasonEnded(season) in event_reader.read() {
+        let slots = season.edict_slots();
+        let mut active_edicts = edicts
+            .iter()
+            .filter(|(_, slot)| slots.contains(slot))
+            .collect::<Vec<_>>();
+        active_edicts.sort_by_key(|(_, slot)| slots.iter().position(|s| s == *slot));
+        if active_edicts.len() < 2 {
+            warn!("{season:?} ended without both of its edicts in play");
+        }
+
+        let score = SeasonScore {
+            season: season.clone(),
+            edicts: active_edicts
+                .into_iter()
+                .map(|(edict, _)| (edict.clone(), score_edict(edict, &board).points))
+                .collect(),
+            // every coin collected so far counts again in each season
+            coins: coins.0,
+            monsters: board.monster_penalty_cells().len() as u32,
+        };
+        info!(
+            "{season:?} scored {} points: {:?}, {} coins and -{} for monsters",
+            score.total(),
+            score.edicts,
+            score.coins,
+            score.monsters
+        );
+        season_scores.0.push(score);
+    }
+}
+
+fn open_scroll_edict(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    scrolls: Query<&EdictSlot, With<Scroll>>,
+    edicts: Query<(&EdictSlot, &Sprite), With<Scoring>>,
+) {
+    let Ok(slot) = scrolls.get(trigger.target()) else {
+        return;
+    };
+    let Some((_, sprite)) = edicts.iter().find(|(edict_slot, _)| *edict_slot == slot) else {
+        return;
+    };
+    let seasons = slot
+        .seasons()
+        .iter()
+        .map(|season| format!("{season:?}"))
+        .collect::<Vec<_>>();
+
+    commands.spawn((
+        ZoomedEdict,
+        StateScoped(AppState::InGame),
+        Button,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(12.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        GlobalZIndex(10),
+        children![
+            (
+                Node {
+                    height: Val::Percent(70.0),
+                    aspect_ratio: Some(0.75),
+                    ..default()
+                },
+                ImageNode::new(sprite.image.clone()),
+            ),
+            Text::new(format!(
+                "Edict {} is scored in {}",
+                slot.letter(),
+                seasons.join(" and ")
+            )),
+        ],
+    ));
+}
+
+fn close_zoomed_edict(
+    mut commands: Commands,
+    interactions: Query<(Entity, &Interaction), With<ZoomedEdict>>,
+) {
+    for (entity, _) in interactions
+        .iter()
+        .filter(|(_, interaction)| **interaction == Interaction::Pressed)
+    {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // one terrain symbol per cell, rows from top to bottom
+    fn board(rows: &[&str]) -> Board {
+        board_with_ruins(rows, &[])
+    }
+
+    fn board_with_ruins(rows: &[&str], ruins: &[(usize, usize)]) -> Board {
+        let cells = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(row, symbols)| {
+                symbols
+                    .chars()
+                    .enumerate()
+                    .map(move |(column, symbol)| Cell {
+                        terrain: Terrain::from_symbol(symbol).expect("terrain symbol"),
+                        index: (row, column),
+                        feature: if ruins.contains(&(row, column)) {
+                            CellFeature::Ruins
+                        } else {
+                            CellFeature::None
+                        },
+                    })
+            })
+            .collect::<Vec<_>>();
+        Board::new(cells.iter(), (rows.len(), rows[0].len()))
+    }
+
+    fn points(edict: Scoring, board: &Board) -> u32 {
+        score_edict(&edict, board).points
+    }
+
+    #[test]
+    fn sentinel_wood_scores_forests_on_the_edge() {
+        let board = board(&["T..", ".T.", "..T"]);
+        let score = score_edict(&Scoring::Tree(TreeScoring::SentinelWood26), &board);
+        assert_eq!(score.points, 2);
+        assert_eq!(score.cells, HashSet::from([(0, 0), (2, 2)]));
+    }
+
+    #[test]
+    fn greenbough_scores_rows_and_columns_with_a_forest() {
+        let board = board(&["T..", "T..", "..T"]);
+        assert_eq!(points(Scoring::Tree(TreeScoring::Greenbough27), &board), 5);
+    }
+
+    #[test]
+    fn treetower_scores_surrounded_forests_with_the_edge_as_filled() {
+        let board = board(&["TV..", "VTV.", ".V..", "...T"]);
+        assert_eq!(points(Scoring::Tree(TreeScoring::Treetower28), &board), 2);
+    }
+
+    #[test]
+    fn stoneside_forest_scores_mountains_connected_by_forests() {
+        let board = board(&["MTTM.", ".....", "MT..."]);
+        assert_eq!(
+            points(Scoring::Tree(TreeScoring::StonesideForest29), &board),
+            6
+        );
+    }
+
+    #[test]
+    fn canal_lake_scores_waters_and_farms_next_to_each_other() {
+        let board = board(&["FW.", "F..", "..W"]);
+        assert_eq!(points(Scoring::Farm(FarmScoring::CanalLake30), &board), 2);
+    }
+
+    #[test]
+    fn mages_valley_scores_waters_and_farms_next_to_mountains() {
+        let board = board(&["WM.", ".F.", "..W"]);
+        assert_eq!(points(Scoring::Farm(FarmScoring::MagesValley31), &board), 3);
+    }
+
+    #[test]
+    fn golden_granary_scores_waters_next_to_ruins_and_farms_on_ruins() {
+        let board = board_with_ruins(&[".W.", "F..", ".WF"], &[(0, 0), (2, 2)]);
+        assert_eq!(
+            points(Scoring::Farm(FarmScoring::TheGoldenGranary32), &board),
+            5
+        );
+    }
+
+    #[test]
+    fn shoreside_expanse_scores_clusters_away_from_the_edge_and_the_other_terrain() {
+        let board = board(&[".....", ".F.W.", ".....", ".FW..", "....F"]);
+        assert_eq!(
+            points(Scoring::Farm(FarmScoring::ShoresideExpanse33), &board),
+            6
+        );
+    }
+
+    #[test]
+    fn wildholds_scores_clusters_of_six_villages() {
+        let board = board(&["VVV.", "VVV.", "....", "VVVV"]);
+        assert_eq!(points(Scoring::House(HouseScoring::Wildholds34), &board), 8);
+    }
+
+    #[test]
+    fn great_city_scores_the_largest_cluster_not_next_to_a_mountain() {
+        let board = board(&["VVVM", "....", "VV.."]);
+        assert_eq!(points(Scoring::House(HouseScoring::GreatCity35), &board), 2);
+    }
+
+    #[test]
+    fn greengold_plains_scores_clusters_next_to_three_terrains() {
+        let board = board(&["TVF.", ".W..", ".V.."]);
+        assert_eq!(
+            points(Scoring::House(HouseScoring::GreengoldPlains36), &board),
+            3
+        );
+    }
+
+    #[test]
+    fn shieldgate_scores_the_second_largest_cluster() {
+        let board = board(&["VVV.", "....", "VV.V"]);
+        assert_eq!(
+            points(Scoring::House(HouseScoring::Shieldgate37), &board),
+            4
+        );
+    }
+
+    #[test]
+    fn borderlands_scores_full_rows_and_columns() {
+        let board = board(&["TTT", "T..", "T.M"]);
+        assert_eq!(
+            points(Scoring::Shape(ShapeScoring::Borderlands38), &board),
+            12
+        );
+    }
+
+    #[test]
+    fn lost_barony_scores_the_side_of_the_largest_filled_square() {
+        let board = board(&["TTT.", "TTV.", "...."]);
+        let score = score_edict(&Scoring::Shape(ShapeScoring::LostBarony39), &board);
+        assert_eq!(score.points, 6);
+        assert_eq!(score.cells.len(), 4);
+    }
+
+    #[test]
+    fn broken_road_scores_full_diagonals_from_the_left_edge() {
+        let board = board(&["T..", ".T.", "T.T"]);
+        assert_eq!(
+            points(Scoring::Shape(ShapeScoring::TheBrokenRoad40), &board),
+            6
+        );
+    }
+
+    #[test]
+    fn cauldrons_scores_surrounded_empty_cells_with_the_edge_as_filled() {
+        let board = board(&[".T..", "T.T.", ".T.."]);
+        assert_eq!(
+            points(Scoring::Shape(ShapeScoring::TheCauldrons41), &board),
+            3
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::asset_manager::CardFronts;
+use crate::cards::{self, Card, DrawableCard};
+use crate::scoring::{EdictSlot, SeasonEnded};
+use crate::{AppState, DrawnCard, TurnPhase, reset_resource};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(Season::default())
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (reset_resource::<Season>, spawn_season_label),
+        )
+        .add_systems(OnEnter(TurnPhase::SeasonScoring), spawn_season_handoff)
+        .add_systems(
+            Update,
+            (
+                advance_time,
+                confirm_season_handoff.run_if(in_state(TurnPhase::SeasonScoring)),
+                update_season_label.run_if(resource_changed::<Season>),
+                highlight_active_edicts
+                    .run_if(resource_changed::<Season>.or(any_match_filter::<Added<EdictSlot>>)),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+#[derive(Debug, Resource)]
+pub struct Season {
+    pub current: cards::Season,
+    pub time: u32,
+}
+
+impl Default for Season {
+    fn default() -> Self {
+        Self {
+            current: cards::Season::Spring18,
+            time: 0,
+        }
+    }
+}
+
+impl Season {
+    pub fn is_over(&self) -> bool {
+        self.time >= self.current.time_threshold()
+    }
+}
+
+#[derive(Component)]
+struct SeasonLabel;
+
+#[derive(Component)]
+struct SeasonHandoff;
+
+pub fn season_in_progress(season: Res<Season>) -> bool {
+    !season.is_over()
+}
+
+// runs right before a card would be drawn, so the card that reaches the
+// threshold is still played in the ending season
+pub fn end_season_when_time_is_up(
+    mut commands: Commands,
+    season: Res<Season>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if !season.is_over() {
+        return;
+    }
+    commands.send_event(SeasonEnded(season.current.clone()));
+    // the last season is still scored in this frame before the game is over
+    match season.current.next() {
+        Some(_) => next_phase.set(TurnPhase::SeasonScoring),
+        None => next_state.set(AppState::GameOver),
+    }
+}
+
+fn spawn_season_handoff(mut commands: Commands, season: Res<Season>, card_fronts: Res<CardFronts>) {
+    let next = season
+        .current
+        .next()
+        .expect("a season after the scored one");
+    let [first, second] = next.edict_slots();
+    commands.spawn((
+        SeasonHandoff,
+        StateScoped(AppState::InGame),
+        Button,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(12.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        GlobalZIndex(10),
+        children![
+            (
+                Node {
+                    height: Val::Percent(60.0),
+                    aspect_ratio: Some(0.75),
+                    ..default()
+                },
+                ImageNode::new(card_fronts[&Card::Season(next.clone())].clone()),
+            ),
+            (
+                Text::new(format!(
+                    "{} begins - edicts {} & {} will be scored",
+                    next.name(),
+                    first.letter(),
+                    second.letter()
+                )),
+                TextFont::from_font_size(32.0),
+            ),
+            Text::new("Click to continue"),
+        ],
+    ));
+}
+
+fn advance_time(
+    drawn_card: Single<&DrawnCard, Changed<DrawnCard>>,
+    cards: Query<&DrawableCard>,
+    mut season: ResMut<Season>,
+    mut counted_card: Local<Option<Entity>>,
+) {
+    // the drawn card is also marked changed to rebuild its choices
+    if *counted_card == Some(drawn_card.0) {
+        return;
+    }
+    *counted_card = Some(drawn_card.0);
+    season.time += cards.get(drawn_card.0).expect("card").time_cost();
+}
+
+fn confirm_season_handoff(
+    mut commands: Commands,
+    handoff: Query<(Entity, &Interaction), With<SeasonHandoff>>,
+    mut season: ResMut<Season>,
+    mut next_phase: ResMut<NextState<TurnPhase>>,
+) {
+    for (entity, _) in handoff
+        .iter()
+        .filter(|(_, interaction)| **interaction == Interaction::Pressed)
+    {
+        commands.entity(entity).despawn();
+        season.current = season
+            .current
+            .next()
+            .expect("a season after the scored one");
+        season.time = 0;
+        next_phase.set(TurnPhase::DrawCard);
+    }
+}
+
+fn spawn_season_label(mut commands: Commands) {
+    commands.spawn((
+        SeasonLabel,
+        StateScoped(AppState::InGame),
+        Text::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Percent(45.0),
+            ..default()
+        },
+    ));
+}
+
+fn update_season_label(season: Res<Season>, mut label: Single<&mut Text, With<SeasonLabel>>) {
+    label.0 = format!(
+        "{}: {} / {}",
+        season.current.name(),
+        season.time,
+        season.current.time_threshold()
+    );
+}
+
+// scrolls and edicts outside the current season are greyed out
+fn highlight_active_edicts(season: Res<Season>, mut slots: Query<(&EdictSlot, &mut Sprite)>) {
+    let active_slots = season.current.edict_slots();
+    for (slot, mut sprite) in slots.iter_mut() {
+        sprite.color = if active_slots.contains(slot) {
+            Color::WHITE
+        } else {
+            Color::srgb(0.4, 0.4, 0.4)
+        };
+    }
+}
@@ -0,0 +1,97 @@
+use crate::placement::Orientation;
+use crate::terrain::Choice;
+
+// moved so the smallest row and column are 0 and sorted, so equal shapes compare equal
+pub fn normalized(tiles: &[(isize, isize)]) -> Vec<(isize, isize)> {
+    let min_row = tiles.iter().map(|(row, _)| *row).min().unwrap_or_default();
+    let min_column = tiles
+        .iter()
+        .map(|(_, column)| *column)
+        .min()
+        .unwrap_or_default();
+    let mut tiles = tiles
+        .iter()
+        .map(|(row, column)| (row - min_row, column - min_column))
+        .collect::<Vec<_>>();
+    tiles.sort();
+    tiles
+}
+
+pub fn oriented(choice: &Choice, orientation: Orientation) -> Vec<(isize, isize)> {
+    normalized(&choice.orientations[&orientation])
+}
+
+// the same for every orientation of a shape, e.g. to tell whether two choices are one shape
+pub fn canonical(choice: &Choice) -> Vec<(isize, isize)> {
+    Orientation::iter()
+        .map(|orientation| oriented(choice, orientation))
+        .min()
+        .expect("at least one orientation")
+}
+
+// the orientations that leave the shape as it is
+pub fn symmetries(choice: &Choice) -> Vec<Orientation> {
+    let shape = oriented(choice, Orientation::default());
+    Orientation::iter()
+        .filter(|orientation| oriented(choice, *orientation) == shape)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::Terrain;
+    use bevy::prelude::Handle;
+
+    fn choice(tiles: &[(usize, usize)]) -> Choice {
+        Choice::new(Terrain::Forest, Handle::default(), tiles.to_vec(), false)
+    }
+
+    fn square() -> Choice {
+        choice(&[(0, 0), (0, 1), (1, 0), (1, 1)])
+    }
+
+    fn l_shape() -> Choice {
+        choice(&[(0, 0), (1, 0), (2, 0), (2, 1)])
+    }
+
+    fn line() -> Choice {
+        choice(&[(0, 0), (0, 1), (0, 2), (0, 3)])
+    }
+
+    #[test]
+    fn a_square_has_one_distinct_orientation() {
+        assert_eq!(square().distinct_orientations.len(), 1);
+        assert_eq!(symmetries(&square()).len(), 8);
+    }
+
+    #[test]
+    fn an_l_shape_has_eight_distinct_orientations() {
+        assert_eq!(l_shape().distinct_orientations.len(), 8);
+        assert_eq!(symmetries(&l_shape()), vec![Orientation::default()]);
+    }
+
+    #[test]
+    fn a_line_is_symmetric_under_half_turns_and_flips() {
+        assert_eq!(line().distinct_orientations.len(), 2);
+        assert_eq!(symmetries(&line()).len(), 4);
+    }
+
+    #[test]
+    fn every_orientation_of_a_shape_has_the_same_canonical_form() {
+        let shape = l_shape();
+        for orientation in Orientation::iter() {
+            let tiles = oriented(&shape, orientation)
+                .into_iter()
+                .map(|(row, column)| (row as usize, column as usize))
+                .collect::<Vec<_>>();
+            assert_eq!(canonical(&choice(&tiles)), canonical(&shape));
+        }
+    }
+
+    #[test]
+    fn different_shapes_have_different_canonical_forms() {
+        assert_ne!(canonical(&l_shape()), canonical(&line()));
+        assert_ne!(canonical(&l_shape()), canonical(&square()));
+    }
+}
@@ -1,5 +1,9 @@
+use crate::map::occupied_tiles;
+use crate::placement::Orientation;
+use crate::shapes::normalized;
 use bevy::prelude::*;
-use strum::EnumIter;
+use std::collections::HashMap;
+use strum::{EnumIter, IntoEnumIterator};
 
 #[derive(Clone, Debug, Default, EnumIter, Eq, Hash, PartialEq)]
 pub enum Terrain {
@@ -19,6 +23,10 @@ pub struct Choice {
     pub image: Handle<Image>,
     pub tiles: Vec<(usize, usize)>,
     pub with_coin: bool,
+    // computed once with the choice: the tiles relative to the hovered cell in
+    // every rotation and mirroring, and one of each that lies differently
+    pub orientations: HashMap<Orientation, Vec<(isize, isize)>>,
+    pub distinct_orientations: Vec<Orientation>,
 }
 
 impl Terrain {
@@ -33,10 +41,75 @@ impl Terrain {
             Terrain::Mountain => "textures/terrain/mountain.png",
         }
     }
+
+    pub fn key(&self) -> String {
+        format!("terrain.{self:?}")
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            Terrain::None => '.',
+            Terrain::Forest => 'T',
+            Terrain::Village => 'V',
+            Terrain::Farm => 'F',
+            Terrain::Water => 'W',
+            Terrain::Monster => 'X',
+            Terrain::Mountain => 'M',
+        }
+    }
+
+    pub fn from_symbol(symbol: char) -> Option<Self> {
+        Terrain::iter().find(|terrain| terrain.symbol() == symbol)
+    }
 }
 
 impl Choice {
-    pub fn size(&self, cell_size: Vec2) -> Vec2 {
+    pub fn new(
+        terrain: Terrain,
+        image: Handle<Image>,
+        tiles: Vec<(usize, usize)>,
+        with_coin: bool,
+    ) -> Self {
+        let mut choice = Self {
+            terrain,
+            image,
+            tiles,
+            with_coin,
+            orientations: HashMap::new(),
+            distinct_orientations: Vec::new(),
+        };
+        choice.orientations = Orientation::iter()
+            .map(|orientation| (orientation, occupied_tiles(&choice, orientation, (0, 0))))
+            .collect();
+        let mut shapes = Vec::new();
+        choice.distinct_orientations = Orientation::iter()
+            .filter(|orientation| {
+                let shape = normalized(&choice.orientations[orientation]);
+                let distinct = !shapes.contains(&shape);
+                if distinct {
+                    shapes.push(shape);
+                }
+                distinct
+            })
+            .collect();
+        choice
+    }
+
+    pub fn tiles_at(
+        &self,
+        orientation: Orientation,
+        (row, column): (usize, usize),
+    ) -> Vec<(isize, isize)> {
+        self.orientations[&orientation]
+            .iter()
+            .map(|(row_offset, column_offset)| {
+                (row as isize + row_offset, column as isize + column_offset)
+            })
+            .collect()
+    }
+
+    // rows and columns spanned by the tiles
+    pub fn dimension(&self) -> (usize, usize) {
         let max_row = self
             .tiles
             .iter()
@@ -49,9 +122,11 @@ impl Choice {
             .map(|(_, column)| *column + 1)
             .max()
             .expect("at least one tile");
-        Vec2::new(
-            max_column as f32 * cell_size.x,
-            max_row as f32 * cell_size.y,
-        )
+        (max_row, max_column)
+    }
+
+    pub fn size(&self, cell_size: Vec2) -> Vec2 {
+        let (rows, columns) = self.dimension();
+        Vec2::new(columns as f32 * cell_size.x, rows as f32 * cell_size.y)
     }
 }
@@ -0,0 +1,170 @@
+use crate::create_choices;
+use crate::framepace::FramepaceProfile;
+use crate::hooks::GameSet;
+use bevy::ecs::schedule::ScheduleConfigs;
+use bevy::ecs::system::ScheduleSystem;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(FrameBudget::default())
+        .insert_resource(Spans::default())
+        .add_systems(
+            Update,
+            (
+                toggle_watchdog.run_if(input_just_pressed(KeyCode::F10)),
+                follow_framepace_profile.run_if(resource_changed::<FramepaceProfile>),
+                watch("choice generation", create_choices),
+                watch("drawing", GameSet::Draw),
+                watch("scoring", GameSet::Scoring),
+            ),
+        )
+        .add_systems(
+            Last,
+            check_frame.run_if(|budget: Res<FrameBudget>| budget.enabled),
+        );
+}
+
+// the frame budget follows the framepace profile, 60 frames per second leave about
+// 16 ms per frame and a single system should only take a fraction of that
+#[derive(Debug, Resource)]
+pub struct FrameBudget {
+    pub enabled: bool,
+    pub frame: Duration,
+    pub system: Duration,
+}
+
+#[derive(Default, Resource)]
+struct Spans {
+    starts: HashMap<&'static str, Instant>,
+    // stays on the overlay until another span goes over budget
+    last_slow: Option<(&'static str, Duration)>,
+    // at most one warning per second for each span and the frame, the rest are counted
+    warnings: HashMap<&'static str, (Option<Instant>, u32)>,
+}
+
+const WARNING_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Component)]
+struct WatchdogOverlay;
+
+impl Default for FrameBudget {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            frame: Duration::from_micros(16_667),
+            system: Duration::from_millis(4),
+        }
+    }
+}
+
+impl Spans {
+    // the number of times the span went over budget since its last warning, if it is
+    // time for the next one
+    fn warning_due(&mut self, name: &'static str) -> Option<u32> {
+        let (last_warning, count) = self.warnings.entry(name).or_default();
+        *count += 1;
+        if last_warning.is_some_and(|last_warning| last_warning.elapsed() < WARNING_INTERVAL) {
+            return None;
+        }
+        *last_warning = Some(Instant::now());
+        Some(std::mem::take(count))
+    }
+}
+
+// times the systems of a set from just before until just after it, so systems running
+// in parallel are counted as well
+fn watch<M>(
+    name: &'static str,
+    set: impl IntoSystemSet<M> + Clone,
+) -> ScheduleConfigs<ScheduleSystem> {
+    let start = move |mut spans: ResMut<Spans>| {
+        spans.starts.insert(name, Instant::now());
+    };
+    let end = move |mut spans: ResMut<Spans>, budget: Res<FrameBudget>| {
+        let Some(start) = spans.starts.remove(name) else {
+            return;
+        };
+        let elapsed = start.elapsed();
+        if elapsed <= budget.system {
+            return;
+        }
+        spans.last_slow = Some((name, elapsed));
+        let Some(count) = spans.warning_due(name) else {
+            return;
+        };
+        warn!(
+            "{name} took {:.1} ms, over the budget of {:.1} ms ({count}x since the last warning)",
+            elapsed.as_secs_f32() * 1000.0,
+            budget.system.as_secs_f32() * 1000.0
+        );
+    };
+    (start.before(set.clone()), end.after(set)).run_if(|budget: Res<FrameBudget>| budget.enabled)
+}
+
+fn toggle_watchdog(
+    mut commands: Commands,
+    mut budget: ResMut<FrameBudget>,
+    overlays: Query<Entity, With<WatchdogOverlay>>,
+) {
+    budget.enabled = !budget.enabled;
+    info!("frame budget watchdog enabled: {}", budget.enabled);
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn();
+    }
+    if budget.enabled {
+        commands.spawn((
+            WatchdogOverlay,
+            Text::default(),
+            TextFont::from_font_size(14.0),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Percent(45.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            GlobalZIndex(20),
+        ));
+    }
+}
+
+fn follow_framepace_profile(profile: Res<FramepaceProfile>, mut budget: ResMut<FrameBudget>) {
+    budget.frame = profile.frame_budget();
+}
+
+fn check_frame(
+    time: Res<Time<Real>>,
+    budget: Res<FrameBudget>,
+    mut spans: ResMut<Spans>,
+    mut overlay: Single<&mut Text, With<WatchdogOverlay>>,
+) {
+    let frame = time.delta();
+    let milliseconds = |duration: Duration| duration.as_secs_f32() * 1000.0;
+    if let Some(count) = (frame > budget.frame)
+        .then(|| spans.warning_due("frame"))
+        .flatten()
+    {
+        warn!(
+            "frame took {:.1} ms, over the budget of {:.1} ms ({count}x since the last warning)",
+            milliseconds(frame),
+            milliseconds(budget.frame)
+        );
+    }
+    let mut text = format!(
+        "frame: {:.1} ms of {:.1} ms",
+        milliseconds(frame),
+        milliseconds(budget.frame)
+    );
+    if let Some((name, elapsed)) = spans.last_slow {
+        text.push_str(&format!(
+            "\nlast slow: {name} {:.1} ms",
+            milliseconds(elapsed)
+        ));
+    }
+    overlay.0 = text;
+}